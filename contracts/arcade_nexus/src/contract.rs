@@ -5,11 +5,11 @@
 mod state;
 
 use linera_sdk::{
-    linera_base_types::WithContractAbi,
+    linera_base_types::{Account, AccountOwner, Amount, ApplicationId, ChainId, WithContractAbi},
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use arcade_nexus::{Operation, ArcadeNexusAbi, PlayerSeasonStats, Quest, QuestCategory, QuestProgress, Season};
+use arcade_nexus::{ActivityKind, ArcadeSkillIndex, GameEventKind, Guild, LimitViolation, Message, NexusError, Operation, OperationResult, ArcadeNexusAbi, PlayerSeasonStats, Quest, QuestCategory, QuestRequirement, QuestTemplate, RegisteredGame, RequirementMetric, RewardClaim, Role, Season, SeasonPhase, SeasonTemplate, FULL_PAYOUT_PPM, MAX_FINALIZE_BATCH, MAX_GUILD_MEMBERS, MAX_QUEST_BATCH, MAX_SKILL_REFRESH_BATCH};
 use state::ArcadeNexusState;
 
 /// The ArcadeNexus contract.
@@ -25,7 +25,7 @@ impl WithContractAbi for ArcadeNexusContract {
 }
 
 impl Contract for ArcadeNexusContract {
-    type Message = ();
+    type Message = Message;
     type InstantiationArgument = ();
     type Parameters = ();
     type EventValue = ();
@@ -38,13 +38,199 @@ impl Contract for ArcadeNexusContract {
     }
 
     async fn instantiate(&mut self, _argument: Self::InstantiationArgument) {
-        // Set the admin to the creator's chain
-        let chain_id = self.runtime.chain_id().to_string();
-        self.state.set_admin(chain_id);
+        // The creator is the first admin
+        let admin = self
+            .runtime
+            .authenticated_signer()
+            .expect("Application must be created by an account owner")
+            .to_string();
+        let now_seconds = self.runtime.system_time().micros() / 1_000_000;
+        self.state.grant_role(&admin, Role::Admin, &admin, now_seconds).await;
     }
 
     async fn execute_operation(&mut self, operation: Self::Operation) -> Self::Response {
-        let owner = self.runtime.chain_id().to_string();
+        let result = match self.runtime.authenticated_signer() {
+            Some(signer) => self.submit_operation(signer, operation).await,
+            None => Err(NexusError::Unauthenticated),
+        };
+        match result {
+            Ok(result) => result,
+            Err(e) => OperationResult::Error(e),
+        }
+    }
+
+    async fn execute_message(&mut self, message: Self::Message) {
+        // Bounced messages carry nothing to undo
+        if self.runtime.message_is_bouncing() == Some(true) {
+            return;
+        }
+
+        // Messages are only sent with authentication
+        let signer = match self.runtime.authenticated_signer() {
+            Some(signer) => signer,
+            None => return,
+        };
+        let origin = self
+            .runtime
+            .message_origin_chain_id()
+            .expect("Incoming message must have an origin chain");
+
+        // Rejected messages have no sender to answer to
+        let _ = self.handle_message(signer, origin, message).await;
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+impl ArcadeNexusContract {
+    /// Apply an operation on the creator chain, where all players, seasons and
+    /// the game registry live, or forward it there from any other chain.
+    async fn submit_operation(
+        &mut self,
+        signer: AccountOwner,
+        operation: Operation,
+    ) -> Result<OperationResult, NexusError> {
+        let hub_chain_id = self.runtime.application_creator_chain_id();
+        let message = match operation {
+            Operation::RecordGameAction { season_id, points } => {
+                // Only game applications may report XP, never users directly
+                let game = self
+                    .runtime
+                    .authenticated_caller_id()
+                    .ok_or(NexusError::NotCalledByApplication)?;
+                Message::RecordGameAction {
+                    game,
+                    season_id,
+                    points,
+                }
+            }
+
+            Operation::RecordGameEvent {
+                season_id,
+                kind,
+                value,
+            } => {
+                // Only game applications may attest events, never users directly
+                let game = self
+                    .runtime
+                    .authenticated_caller_id()
+                    .ok_or(NexusError::NotCalledByApplication)?;
+                Message::RecordGameEvent {
+                    game,
+                    season_id,
+                    kind,
+                    value,
+                }
+            }
+
+            Operation::FundPrizePool { season_id, amount } => {
+                // The deposit leaves the signer's account here; the prize pool
+                // is held in the application's account on the creator chain
+                let pool = Account {
+                    chain_id: hub_chain_id,
+                    owner: self.application_owner(),
+                };
+                self.runtime.transfer(signer, pool, amount);
+                Message::FundPrizePool { season_id, amount }
+            }
+
+            operation => Message::Operation(Box::new(operation)),
+        };
+
+        if self.runtime.chain_id() == hub_chain_id {
+            return self.handle_message(signer, hub_chain_id, message).await;
+        }
+        self.runtime
+            .prepare_message(message)
+            .with_authentication()
+            .send_to(hub_chain_id);
+        Ok(OperationResult::ActionForwarded)
+    }
+
+    /// Apply a request of `signer` submitted on the chain `origin`.
+    async fn handle_message(
+        &mut self,
+        signer: AccountOwner,
+        origin: ChainId,
+        message: Message,
+    ) -> Result<OperationResult, NexusError> {
+        let now_seconds = (self.runtime.system_time().micros() / 1_000_000) as i64;
+        let player = signer.to_string();
+
+        match message {
+            Message::Operation(operation) => self.apply_operation(signer, origin, *operation).await,
+
+            Message::RecordGameAction {
+                game,
+                season_id,
+                points,
+            } => {
+                // An explicit season reports its error; otherwise each live
+                // season is credited as far as its own limits allow
+                for target in self.target_seasons(season_id, now_seconds).await? {
+                    let recorded = self.record_game_xp(game, target, &player, points, now_seconds).await;
+                    if season_id.is_some() {
+                        recorded?;
+                    }
+                }
+                Ok(OperationResult::ActionRecorded)
+            }
+
+            Message::RecordGameEvent {
+                game,
+                season_id,
+                kind,
+                value,
+            } => {
+                for target in self.target_seasons(season_id, now_seconds).await? {
+                    let recorded = self.record_game_event(game, target, &player, kind, value, now_seconds).await;
+                    if season_id.is_some() {
+                        recorded?;
+                    }
+                }
+                Ok(OperationResult::ActionRecorded)
+            }
+
+            Message::FundPrizePool { season_id, amount } => {
+                match self.fund_prize_pool(season_id, amount).await {
+                    Ok(prize_pool) => Ok(OperationResult::PrizePoolFunded { prize_pool }),
+                    Err(e) => {
+                        // Return the deposit to the account it came from
+                        let source = self.application_owner();
+                        let refund = Account {
+                            chain_id: origin,
+                            owner: signer,
+                        };
+                        self.runtime.transfer(source, refund, amount);
+                        Err(e)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Seasons a game report applies to: the one it names, or every live season.
+    async fn target_seasons(&self, season_id: Option<u64>, now_seconds: i64) -> Result<Vec<u64>, NexusError> {
+        let season_ids = match season_id {
+            Some(season_id) => vec![season_id],
+            None => self.state.get_live_season_ids(now_seconds).await,
+        };
+        if season_ids.is_empty() {
+            return Err(NexusError::NoLiveSeason);
+        }
+        Ok(season_ids)
+    }
+
+    /// Validate and apply an operation of `signer` on the creator chain.
+    async fn apply_operation(
+        &mut self,
+        signer: AccountOwner,
+        origin: ChainId,
+        operation: Operation,
+    ) -> Result<OperationResult, NexusError> {
+        let owner = signer.to_string();
         let now_micros = self.runtime.system_time().micros();
         // Convert microseconds to seconds for time comparisons
         let now_seconds = (now_micros / 1_000_000) as i64;
@@ -56,16 +242,32 @@ impl Contract for ArcadeNexusContract {
                 start_time,
                 end_time,
                 theme,
+                grace_period,
+                rollover,
+                reward_tiers,
+                skill_config,
+                limits,
             } => {
-                // Only admin can create seasons (for now, allow anyone)
-                // let admin = self.state.get_admin();
-                // if admin.as_ref() != Some(&owner) {
-                //     return; // Not admin
-                // }
+                // Only season managers can create seasons
+                self.require_role(&owner, Role::SeasonManager).await?;
 
                 // Validate times
-                if end_time <= start_time {
-                    return; // Invalid time range
+                let grace_period = grace_period.unwrap_or(0);
+                if end_time <= start_time || grace_period < 0 {
+                    return Err(NexusError::InvalidTimeRange);
+                }
+                if let Some(template) = &rollover {
+                    if template.duration <= 0 || template.grace_period < 0 || template.gap < 0 {
+                        return Err(NexusError::InvalidRolloverTemplate);
+                    }
+                }
+                if reward_tiers.iter().flatten().any(|tier| !tier.is_valid()) {
+                    return Err(NexusError::InvalidRewardTiers);
+                }
+
+                // Seasons of the same theme must not overlap
+                if self.state.has_overlapping_season(&theme, start_time, end_time).await {
+                    return Err(NexusError::OverlappingSeason);
                 }
 
                 let season_id = self.state.get_next_season_id().await;
@@ -78,62 +280,244 @@ impl Contract for ArcadeNexusContract {
                     end_time,
                     active: true,
                     theme,
+                    grace_period,
+                    finalized_at: None,
+                    archived: false,
+                    rollover,
+                    reward_tiers: reward_tiers.unwrap_or_default(),
+                    prize_pool: Amount::ZERO,
+                    ranked_players: 0,
+                    payout_ppm: FULL_PAYOUT_PPM,
+                    ranks_snapshotted: 0,
+                    snapshot_cursor: None,
+                    previous_season: None,
+                    skill_config: skill_config.unwrap_or_default(),
+                    limits: limits.unwrap_or_default(),
                 };
                 self.state.save_season(season).await;
+                self.state
+                    .log_activity(ActivityKind::SeasonCreated, season_id, None, 0, None, None, now_seconds)
+                    .await;
+                Ok(OperationResult::SeasonCreated { season_id })
             }
 
             Operation::CloseSeason { season_id } => {
-                // Only admin can close seasons (for now, allow anyone)
-                // let admin = self.state.get_admin();
-                // if admin.as_ref() != Some(&owner) {
-                //     return; // Not admin
-                // }
-
-                if let Some(mut season) = self.state.get_season(season_id).await {
-                    season.active = false;
-                    self.state.save_season(season).await;
-
-                    // Optionally set rank snapshots for top players
-                    let leaderboard = self.state.get_leaderboard(season_id, 100).await;
-                    for (rank, mut stats) in leaderboard.into_iter().enumerate() {
-                        stats.rank_snapshot = Some((rank + 1) as u32);
-                        self.state.save_player_stats(stats).await;
+                // Only season managers can close seasons
+                self.require_role(&owner, Role::SeasonManager).await?;
+
+                let season = match self.state.get_season(season_id).await {
+                    Some(s) if s.active => s,
+                    Some(_) => return Err(NexusError::SeasonInactive),
+                    None => return Err(NexusError::SeasonNotFound),
+                };
+                let ranks_pending = self.finalize_season(season, now_seconds).await;
+                Ok(OperationResult::SeasonFinalized {
+                    season_id,
+                    next_season_id: None,
+                    ranks_pending,
+                })
+            }
+
+            Operation::FinalizeSeason { season_id } => {
+                // Anyone may finalize, but only once the grace period is over
+                let season = match self.state.get_season(season_id).await {
+                    Some(s) if s.snapshot_pending() => {
+                        // Already finalized; continue snapshotting ranks
+                        let ranks_pending = self.snapshot_ranks(s, now_seconds).await;
+                        return Ok(OperationResult::SeasonFinalized {
+                            season_id,
+                            next_season_id: None,
+                            ranks_pending,
+                        });
                     }
+                    Some(s) if s.active => s,
+                    Some(_) => return Err(NexusError::SeasonInactive),
+                    None => return Err(NexusError::SeasonNotFound),
+                };
+                if season.phase(now_seconds) != SeasonPhase::Closed {
+                    return Err(NexusError::SeasonNotExpired);
                 }
+
+                let previous = season.clone();
+                let ranks_pending = self.finalize_season(season, now_seconds).await;
+
+                let next_season_id = match previous.rollover.clone() {
+                    Some(template) => self.create_rollover_season(template, &previous, now_seconds).await,
+                    None => None,
+                };
+                Ok(OperationResult::SeasonFinalized {
+                    season_id,
+                    next_season_id,
+                    ranks_pending,
+                })
             }
 
-            Operation::RecordGameAction {
-                season_id,
-                category,
-                points,
-            } => {
-                // Check season exists and is active
-                let season = match self.state.get_season(season_id).await {
+            Operation::ArchiveSeason { season_id } => {
+                // Only season managers can archive seasons
+                self.require_role(&owner, Role::SeasonManager).await?;
+
+                let mut season = match self.state.get_season(season_id).await {
+                    Some(s) if s.archived => return Err(NexusError::SeasonArchived),
+                    Some(s) if s.snapshot_pending() => return Err(NexusError::RanksPending),
+                    Some(s) if s.finalized_at.is_some() => s,
+                    Some(_) => return Err(NexusError::SeasonNotFinalized),
+                    None => return Err(NexusError::SeasonNotFound),
+                };
+                season.archived = true;
+                self.state.save_season(season).await;
+                self.state
+                    .log_activity(ActivityKind::SeasonArchived, season_id, None, 0, None, None, now_seconds)
+                    .await;
+                Ok(OperationResult::SeasonUpdated)
+            }
+
+            Operation::SetRewardTiers { season_id, tiers } => {
+                // Only season managers can configure rewards
+                self.require_role(&owner, Role::SeasonManager).await?;
+
+                // Tiers are frozen once ranks have been snapshotted
+                let mut season = match self.state.get_season(season_id).await {
                     Some(s) if s.active => s,
-                    _ => return, // Season not found or not active
+                    Some(_) => return Err(NexusError::SeasonInactive),
+                    None => return Err(NexusError::SeasonNotFound),
                 };
+                if tiers.iter().any(|tier| !tier.is_valid()) {
+                    return Err(NexusError::InvalidRewardTiers);
+                }
+                season.reward_tiers = tiers;
+                let ranked_players = self.state.get_ranked_count(season_id).await;
+                if !season.prize_pool_covers_tiers(ranked_players) {
+                    return Err(NexusError::RewardTiersExceedPrizePool);
+                }
+                self.state.save_season(season).await;
+                Ok(OperationResult::SeasonUpdated)
+            }
+
+            Operation::SetActionLimits { season_id, limits } => {
+                // Only season managers can configure limits
+                self.require_role(&owner, Role::SeasonManager).await?;
+
+                let mut season = match self.state.get_season(season_id).await {
+                    Some(s) if s.active => s,
+                    Some(_) => return Err(NexusError::SeasonInactive),
+                    None => return Err(NexusError::SeasonNotFound),
+                };
+                season.limits = limits;
+                self.state.save_season(season).await;
+                Ok(OperationResult::SeasonUpdated)
+            }
 
-                // Check if current time is within season
-                if now_seconds < season.start_time || now_seconds > season.end_time {
-                    return; // Outside season time
+            Operation::RefreshSkillIndex { season_id, owner: player } => {
+                // Only players with recorded stats have an index
+                if !self.state.has_player_stats(&player, season_id).await {
+                    return Err(NexusError::NoStats);
                 }
 
-                // Get or create player stats
-                let mut stats = self.state.get_player_stats(&owner, season_id).await;
+                let stats = self.state.get_player_stats(&player, season_id).await;
+                let index = self.refresh_skill_index(&stats, now_seconds).await?;
+                Ok(OperationResult::SkillIndexRefreshed {
+                    overall_score: index.overall_score,
+                })
+            }
 
-                // Add points to total and category-specific score
-                stats.total_xp += points;
-                match category {
-                    QuestCategory::Prediction => stats.prediction_score += points,
-                    QuestCategory::Meme => stats.meme_score += points,
-                    QuestCategory::Typing => stats.typing_score += points,
-                    QuestCategory::Life => stats.life_score += points,
-                    QuestCategory::Mixed | QuestCategory::Other => {
-                        // For mixed/other, just add to total (already done above)
-                    }
+            Operation::RefreshSkillIndexes { season_id, after } => {
+                if self.state.get_season(season_id).await.is_none() {
+                    return Err(NexusError::SeasonNotFound);
                 }
 
-                self.state.save_player_stats(stats).await;
+                let batch = self
+                    .state
+                    .get_leaderboard_page(season_id, after.as_deref(), MAX_SKILL_REFRESH_BATCH)
+                    .await
+                    .ok_or(NexusError::InvalidCursor)?;
+                let next = (batch.len() == MAX_SKILL_REFRESH_BATCH)
+                    .then(|| batch.last().map(ArcadeNexusState::encode_cursor))
+                    .flatten();
+                for stats in &batch {
+                    self.refresh_skill_index(stats, now_seconds).await?;
+                }
+                Ok(OperationResult::SkillIndexesRefreshed { next })
+            }
+
+            Operation::ClaimSeasonReward { season_id } => {
+                let mut season = match self.state.get_season(season_id).await {
+                    Some(s) if s.snapshot_pending() => return Err(NexusError::RanksPending),
+                    Some(s) if s.finalized_at.is_some() => s,
+                    Some(_) => return Err(NexusError::SeasonNotFinalized),
+                    None => return Err(NexusError::SeasonNotFound),
+                };
+
+                // Rewards can only be paid once
+                if self.state.get_reward_claim(&owner, season_id).await.is_some() {
+                    return Err(NexusError::AlreadyClaimed);
+                }
+
+                let stats = self.state.get_player_stats(&owner, season_id).await;
+                let rank = match stats.rank_snapshot {
+                    Some(rank) => rank,
+                    None => return Err(NexusError::NotRanked),
+                };
+                let amount = match season.reward_for_rank(rank) {
+                    Some(amount) => amount,
+                    None => return Err(NexusError::NoRewardForRank),
+                };
+                season.prize_pool = match season.prize_pool.try_sub(amount) {
+                    Ok(remaining) => remaining,
+                    Err(_) => return Err(NexusError::PrizePoolExhausted),
+                };
+
+                // Pay the claimant's account on the chain they claimed from
+                let destination = Account {
+                    chain_id: origin,
+                    owner: signer,
+                };
+                let source = self.application_owner();
+                self.runtime.transfer(source, destination, amount);
+
+                self.state.save_season(season).await;
+                self.state
+                    .save_reward_claim(RewardClaim {
+                        owner: owner.clone(),
+                        season_id,
+                        rank,
+                        amount,
+                        claimed_at: now_seconds as u64,
+                    })
+                    .await;
+                Ok(OperationResult::RewardClaimed { amount })
+            }
+
+            Operation::RecordGameAction { .. }
+            | Operation::RecordGameEvent { .. }
+            | Operation::FundPrizePool { .. } => {
+                // Sent as their own messages, see `submit_operation`
+                Err(NexusError::NotForwardable)
+            }
+
+            Operation::RegisterGame {
+                application_id,
+                name,
+                category,
+            } => {
+                // Only admin can manage the game registry
+                self.require_role(&owner, Role::Admin).await?;
+
+                let game = RegisteredGame {
+                    application_id,
+                    name,
+                    category,
+                    registered_at: now_seconds as u64,
+                };
+                self.state.save_registered_game(game).await;
+                Ok(OperationResult::RegistryUpdated)
+            }
+
+            Operation::UnregisterGame { application_id } => {
+                // Only admin can manage the game registry
+                self.require_role(&owner, Role::Admin).await?;
+
+                self.state.remove_registered_game(&application_id).await;
+                Ok(OperationResult::RegistryUpdated)
             }
 
             Operation::CreateQuest {
@@ -143,11 +527,50 @@ impl Contract for ArcadeNexusContract {
                 category,
                 reward_xp,
                 requirements_text,
+                requirement,
                 ai_suggested,
+                starts_at,
+                ends_at,
+                max_completions,
+                cooldown,
+                prerequisite,
+                milestones,
+                generator_id,
+                prompt_hash,
             } => {
+                // Only quest authors can create quests
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
                 // Check season exists
                 if self.state.get_season(season_id).await.is_none() {
-                    return; // Season not found
+                    return Err(NexusError::SeasonNotFound);
+                }
+
+                // AI-suggested quests must be verifiable
+                if ai_suggested && requirement.is_none() {
+                    return Err(NexusError::MissingRequirement);
+                }
+
+                // Validate the quest window and repeat cooldown
+                if let (Some(start), Some(end)) = (starts_at, ends_at) {
+                    if end <= start {
+                        return Err(NexusError::InvalidTimeRange);
+                    }
+                }
+                if cooldown.is_some_and(|c| c < 0) {
+                    return Err(NexusError::InvalidCooldown);
+                }
+                let milestones = milestones.unwrap_or_default();
+                if !Quest::milestones_valid(requirement.as_ref(), &milestones) {
+                    return Err(NexusError::InvalidMilestones);
+                }
+
+                // Chained quests must unlock from a quest of the same season
+                if let Some(prerequisite) = prerequisite {
+                    match self.state.get_quest(prerequisite).await {
+                        Some(q) if q.season_id == season_id => {}
+                        _ => return Err(NexusError::PrerequisiteNotFound),
+                    }
                 }
 
                 let quest_id = self.state.get_next_quest_id().await;
@@ -160,66 +583,1242 @@ impl Contract for ArcadeNexusContract {
                     category,
                     reward_xp,
                     requirements_text,
+                    requirement,
                     created_by: owner,
                     active: true,
+                    retired: false,
                     ai_suggested,
                     created_at: now_seconds as u64,
+                    starts_at,
+                    ends_at,
+                    max_completions,
+                    completions: 0,
+                    cooldown,
+                    prerequisite,
+                    milestones,
+                    generator_id,
+                    template_id: None,
+                    prompt_hash,
+                };
+                self.state.save_quest(quest).await;
+                Ok(OperationResult::QuestCreated { quest_id })
+            }
+
+            Operation::CreateQuestTemplate {
+                name,
+                title,
+                description,
+                requirements_text,
+                category,
+                metric,
+                min_target,
+                max_target,
+                min_reward_xp,
+                max_reward_xp,
+            } => {
+                // Only quest authors can create templates
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                let template = QuestTemplate {
+                    id: 0,
+                    name,
+                    title,
+                    description,
+                    requirements_text,
+                    category,
+                    metric,
+                    min_target,
+                    max_target,
+                    min_reward_xp,
+                    max_reward_xp,
+                    created_by: owner,
+                    created_at: now_seconds as u64,
+                    active: true,
                 };
+                if !template.bounds_valid() {
+                    return Err(NexusError::InvalidTemplateBounds);
+                }
+
+                let template_id = self.state.get_next_template_id().await;
+                self.state
+                    .save_quest_template(QuestTemplate { id: template_id, ..template })
+                    .await;
+                Ok(OperationResult::QuestTemplateCreated { template_id })
+            }
+
+            Operation::RetireQuestTemplate { template_id } => {
+                // Only quest authors can retire templates
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                let mut template = self
+                    .state
+                    .get_quest_template(template_id)
+                    .await
+                    .ok_or(NexusError::TemplateNotFound)?;
+                template.active = false;
+                self.state.save_quest_template(template).await;
+                Ok(OperationResult::QuestTemplateUpdated)
+            }
+
+            Operation::CreateQuestsFromTemplates {
+                season_id,
+                generator_id,
+                quests,
+            } => {
+                // Only quest authors can create quests
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                if self.state.get_season(season_id).await.is_none() {
+                    return Err(NexusError::SeasonNotFound);
+                }
+                if quests.is_empty() || quests.len() > MAX_QUEST_BATCH {
+                    return Err(NexusError::InvalidBatchSize);
+                }
+
+                // Validate the whole batch before creating anything
+                let mut filled = Vec::new();
+                for instance in quests {
+                    let template = match self.state.get_quest_template(instance.template_id).await {
+                        Some(t) if t.active => t,
+                        Some(_) => return Err(NexusError::TemplateRetired),
+                        None => return Err(NexusError::TemplateNotFound),
+                    };
+                    if !(template.min_target..=template.max_target).contains(&instance.target)
+                        || !(template.min_reward_xp..=template.max_reward_xp).contains(&instance.reward_xp)
+                    {
+                        return Err(NexusError::OutsideTemplateBounds);
+                    }
+                    if let (Some(start), Some(end)) = (instance.starts_at, instance.ends_at) {
+                        if end <= start {
+                            return Err(NexusError::InvalidTimeRange);
+                        }
+                    }
+
+                    let (title, description, requirements_text) = match (
+                        template.fill(&template.title, &instance),
+                        template.fill(&template.description, &instance),
+                        template.fill(&template.requirements_text, &instance),
+                    ) {
+                        (Some(t), Some(d), Some(r)) => (t, d, r),
+                        _ => return Err(NexusError::UnfilledTemplateSlot),
+                    };
+
+                    filled.push(Quest {
+                        id: 0,
+                        season_id,
+                        title,
+                        description,
+                        category: template.category,
+                        reward_xp: instance.reward_xp,
+                        requirements_text,
+                        requirement: Some(QuestRequirement {
+                            metric: template.metric,
+                            target: instance.target,
+                        }),
+                        created_by: owner.clone(),
+                        active: true,
+                        retired: false,
+                        ai_suggested: true,
+                        created_at: now_seconds as u64,
+                        starts_at: instance.starts_at,
+                        ends_at: instance.ends_at,
+                        max_completions: instance.max_completions,
+                        completions: 0,
+                        cooldown: None,
+                        prerequisite: None,
+                        milestones: Vec::new(),
+                        generator_id: Some(generator_id.clone()),
+                        template_id: Some(template.id),
+                        prompt_hash: instance.prompt_hash,
+                    });
+                }
+
+                let mut quest_ids = Vec::new();
+                for quest in filled {
+                    let quest_id = self.state.get_next_quest_id().await;
+                    self.state.save_quest(Quest { id: quest_id, ..quest }).await;
+                    quest_ids.push(quest_id);
+                }
+                Ok(OperationResult::QuestsCreated { quest_ids })
+            }
+
+            Operation::UpdateQuest {
+                quest_id,
+                title,
+                description,
+                reward_xp,
+                requirements_text,
+                requirement,
+                starts_at,
+                ends_at,
+                max_completions,
+                cooldown,
+                milestones,
+            } => {
+                // Only quest authors can edit quests
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                let mut quest = match self.state.get_quest(quest_id).await {
+                    Some(q) if !q.retired => q,
+                    Some(_) => return Err(NexusError::QuestRetired),
+                    None => return Err(NexusError::QuestNotFound),
+                };
+
+                if let Some(title) = title {
+                    quest.title = title;
+                }
+                if let Some(description) = description {
+                    quest.description = description;
+                }
+                if let Some(reward_xp) = reward_xp {
+                    quest.reward_xp = reward_xp;
+                }
+                if let Some(requirements_text) = requirements_text {
+                    quest.requirements_text = requirements_text;
+                }
+                if let Some(requirement) = requirement {
+                    quest.requirement = requirement.value;
+                }
+                if let Some(starts_at) = starts_at {
+                    quest.starts_at = starts_at.value;
+                }
+                if let Some(ends_at) = ends_at {
+                    quest.ends_at = ends_at.value;
+                }
+                if let Some(max_completions) = max_completions {
+                    quest.max_completions = max_completions.value;
+                }
+                if let Some(cooldown) = cooldown {
+                    quest.cooldown = cooldown.value;
+                }
+                if let Some(milestones) = milestones {
+                    quest.milestones = milestones;
+                }
+
+                // Re-validate the edited quest
+                if quest.ai_suggested && quest.requirement.is_none() {
+                    return Err(NexusError::MissingRequirement);
+                }
+                if let (Some(start), Some(end)) = (quest.starts_at, quest.ends_at) {
+                    if end <= start {
+                        return Err(NexusError::InvalidTimeRange);
+                    }
+                }
+                if quest.cooldown.is_some_and(|c| c < 0) {
+                    return Err(NexusError::InvalidCooldown);
+                }
+                if !Quest::milestones_valid(quest.requirement.as_ref(), &quest.milestones) {
+                    return Err(NexusError::InvalidMilestones);
+                }
+
+                self.state.save_quest(quest).await;
+                Ok(OperationResult::QuestUpdated)
+            }
+
+            Operation::PauseQuest { quest_id } => {
+                // Only quest authors can pause quests
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                let mut quest = self.state.get_quest(quest_id).await.ok_or(NexusError::QuestNotFound)?;
+                quest.active = false;
+                self.state.save_quest(quest).await;
+                Ok(OperationResult::QuestUpdated)
+            }
+
+            Operation::ResumeQuest { quest_id } => {
+                // Only quest authors can resume quests
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                // Retired quests stay retired
+                let mut quest = self.state.get_quest(quest_id).await.ok_or(NexusError::QuestNotFound)?;
+                if quest.retired {
+                    return Err(NexusError::QuestRetired);
+                }
+                quest.active = true;
+                self.state.save_quest(quest).await;
+                Ok(OperationResult::QuestUpdated)
+            }
+
+            Operation::RetireQuest { quest_id } => {
+                // Only quest authors can retire quests
+                self.require_role(&owner, Role::QuestAuthor).await?;
+
+                let mut quest = self.state.get_quest(quest_id).await.ok_or(NexusError::QuestNotFound)?;
+                quest.active = false;
+                quest.retired = true;
                 self.state.save_quest(quest).await;
+                Ok(OperationResult::QuestUpdated)
             }
 
             Operation::CompleteQuest { quest_id } => {
                 // Get quest
-                let quest = match self.state.get_quest(quest_id).await {
+                let mut quest = match self.state.get_quest(quest_id).await {
                     Some(q) if q.active => q,
-                    _ => return, // Quest not found or not active
+                    Some(_) => return Err(NexusError::QuestInactive),
+                    None => return Err(NexusError::QuestNotFound),
                 };
 
-                // Check season is active
+                // Check the quest's own window and completion cap
+                if !quest.is_open(now_seconds) {
+                    return Err(NexusError::OutsideQuestWindow);
+                }
+                if quest.is_full() {
+                    return Err(NexusError::QuestFull);
+                }
+
+                // Quests can be claimed while the season is live or in grace
                 let season = match self.state.get_season(quest.season_id).await {
-                    Some(s) if s.active => s,
-                    _ => return, // Season not active
+                    Some(s) if matches!(s.phase(now_seconds), SeasonPhase::Live | SeasonPhase::Grace) => s,
+                    Some(_) => return Err(NexusError::SeasonInactive),
+                    None => return Err(NexusError::SeasonNotFound),
                 };
 
-                // Check if already completed
-                let progress = self.state.get_quest_progress(&owner, quest_id).await;
+                // Chained quests unlock once the prerequisite is completed
+                if let Some(prerequisite) = quest.prerequisite {
+                    if !self.state.get_quest_progress(&owner, prerequisite).await.completed {
+                        return Err(NexusError::PrerequisiteNotCompleted);
+                    }
+                }
+
+                // Check if already completed, or still cooling down for repeatable quests
+                let mut progress = self.state.get_quest_progress(&owner, quest_id).await;
                 if progress.completed {
-                    return; // Already completed
+                    let cooldown = match quest.cooldown {
+                        Some(cooldown) => cooldown,
+                        None => return Err(NexusError::QuestAlreadyCompleted),
+                    };
+                    if now_seconds < progress.completed_at.unwrap_or(0) + cooldown {
+                        return Err(NexusError::CooldownNotElapsed);
+                    }
+                }
+
+                // Check the requirement against the player's season stats
+                // (repeats count only progress made since the last completion)
+                let mut stats = self.state.get_player_stats(&owner, quest.season_id).await;
+                if let Some(requirement) = quest.requirement {
+                    if !requirement.is_met(&stats, progress.baseline) {
+                        return Err(NexusError::RequirementNotMet);
+                    }
                 }
 
+                // Enforce the season's anti-abuse limits
+                self.check_action_limits(&owner, &season, quest.category, quest.reward_xp, false, now_seconds)
+                    .await?;
+
                 // Mark quest as completed
-                let new_progress = QuestProgress {
-                    quest_id,
-                    owner: owner.clone(),
-                    completed: true,
-                    completed_at: Some(now_seconds),
-                };
-                self.state.save_quest_progress(new_progress).await;
+                progress.completed = true;
+                progress.completed_at = Some(now_seconds);
+                progress.times_completed += 1;
+                if let Some(requirement) = quest.requirement {
+                    progress.progress = stats.metric_value(requirement.metric);
+                    progress.baseline = progress.progress;
+                }
+                self.state.save_quest_progress(progress).await;
 
                 // Award XP
-                let mut stats = self.state.get_player_stats(&owner, quest.season_id).await;
-                stats.total_xp += quest.reward_xp;
+                self.state
+                    .log_activity(
+                        ActivityKind::QuestCompleted,
+                        quest.season_id,
+                        Some(&owner),
+                        quest.reward_xp,
+                        Some(quest_id),
+                        None,
+                        now_seconds,
+                    )
+                    .await;
+                stats.add_xp(quest.category, quest.reward_xp);
                 stats.completed_quests += 1;
+                stats.last_active_at = now_seconds as u64;
 
-                // Also add to category score
-                match quest.category {
-                    QuestCategory::Prediction => stats.prediction_score += quest.reward_xp,
-                    QuestCategory::Meme => stats.meme_score += quest.reward_xp,
-                    QuestCategory::Typing => stats.typing_score += quest.reward_xp,
-                    QuestCategory::Life => stats.life_score += quest.reward_xp,
-                    QuestCategory::Mixed | QuestCategory::Other => {}
+                self.credit_guild_xp(&owner, quest.season_id, quest.reward_xp).await;
+                self.refresh_skill_index(&stats, now_seconds).await?;
+                self.state.save_player_stats(stats).await;
+
+                // Take a completion slot
+                quest.completions += 1;
+                let reward_xp = quest.reward_xp;
+                self.state.save_quest(quest).await;
+                Ok(OperationResult::QuestCompleted { reward_xp })
+            }
+
+            Operation::CreateGuild {
+                name,
+                max_members,
+                invite_only,
+            } => {
+                // Players can only be in one guild at a time
+                if self.state.get_guild_id_of(&owner).await.is_some() {
+                    return Err(NexusError::AlreadyInGuild);
                 }
 
-                self.state.save_player_stats(stats).await;
+                let name = name.trim().to_string();
+                if name.is_empty() || name.len() > 32 {
+                    return Err(NexusError::InvalidGuildName);
+                }
+                let max_members = max_members.unwrap_or(MAX_GUILD_MEMBERS);
+                if max_members == 0 || max_members > MAX_GUILD_MEMBERS {
+                    return Err(NexusError::InvalidMemberLimit);
+                }
+
+                let guild_id = self.state.get_next_guild_id().await;
+                let guild = Guild {
+                    id: guild_id,
+                    name,
+                    leader: owner.clone(),
+                    members: vec![owner.clone()],
+                    max_members,
+                    invite_only,
+                    created_at: now_seconds as u64,
+                };
+                self.state.save_guild(guild).await;
+                self.state.set_guild_member(&owner, guild_id).await;
+                self.move_guild_member_xp(&owner, guild_id, true).await;
+                Ok(OperationResult::GuildCreated { guild_id })
+            }
+
+            Operation::InviteToGuild { player } => {
+                // Only the leader can invite
+                let guild = match self.state.get_guild_id_of(&owner).await {
+                    Some(id) => self.state.get_guild(id).await,
+                    None => None,
+                };
+                let guild = match guild {
+                    Some(g) if g.leader == owner => g,
+                    _ => return Err(NexusError::NotGuildLeader),
+                };
+                if guild.members.contains(&player) {
+                    return Err(NexusError::AlreadyGuildMember);
+                }
+
+                self.state.save_guild_invite(&player, guild.id, now_seconds as u64).await;
+                Ok(OperationResult::GuildUpdated)
+            }
+
+            Operation::JoinGuild { guild_id } => {
+                if self.state.get_guild_id_of(&owner).await.is_some() {
+                    return Err(NexusError::AlreadyInGuild);
+                }
+
+                let mut guild = match self.state.get_guild(guild_id).await {
+                    Some(g) => g,
+                    None => return Err(NexusError::GuildNotFound),
+                };
+                if guild.members.len() as u32 >= guild.max_members {
+                    return Err(NexusError::GuildFull);
+                }
+                let invited = self.state.has_guild_invite(&owner, guild_id).await;
+                if guild.invite_only && !invited {
+                    return Err(NexusError::InviteRequired);
+                }
+
+                guild.members.push(owner.clone());
+                self.state.save_guild(guild).await;
+                self.state.set_guild_member(&owner, guild_id).await;
+                self.move_guild_member_xp(&owner, guild_id, true).await;
+                if invited {
+                    self.state.remove_guild_invite(&owner, guild_id).await;
+                }
+                Ok(OperationResult::GuildUpdated)
+            }
+
+            Operation::LeaveGuild => {
+                let guild_id = match self.state.get_guild_id_of(&owner).await {
+                    Some(id) => id,
+                    None => return Err(NexusError::NotInGuild),
+                };
+                self.state.remove_guild_member(&owner).await;
+                self.move_guild_member_xp(&owner, guild_id, false).await;
+
+                if let Some(mut guild) = self.state.get_guild(guild_id).await {
+                    guild.members.retain(|m| *m != owner);
+                    if guild.members.is_empty() {
+                        // Last member out disbands the guild, dropping it from every
+                        // season's guild leaderboard and voiding pending invites
+                        self.state.remove_guild(guild_id).await;
+                    } else {
+                        if guild.leader == owner {
+                            guild.leader = guild.members[0].clone();
+                        }
+                        self.state.save_guild(guild).await;
+                    }
+                }
+                Ok(OperationResult::GuildUpdated)
+            }
+
+            Operation::KickFromGuild { player } => {
+                if player == owner {
+                    return Err(NexusError::CannotKickSelf);
+                }
+
+                // Only the leader can remove members
+                let guild = match self.state.get_guild_id_of(&owner).await {
+                    Some(id) => self.state.get_guild(id).await,
+                    None => None,
+                };
+                let mut guild = match guild {
+                    Some(g) if g.leader == owner => g,
+                    _ => return Err(NexusError::NotGuildLeader),
+                };
+                if !guild.members.contains(&player) {
+                    return Err(NexusError::NotGuildMember);
+                }
+
+                guild.members.retain(|m| *m != player);
+                let guild_id = guild.id;
+                self.state.save_guild(guild).await;
+                self.state.remove_guild_member(&player).await;
+                self.move_guild_member_xp(&player, guild_id, false).await;
+                Ok(OperationResult::GuildUpdated)
+            }
+
+            Operation::GrantRole { member, role } => {
+                // Only admin can manage roles
+                self.require_role(&owner, Role::Admin).await?;
+
+                self.state.grant_role(&member, role, &owner, now_seconds as u64).await;
+                Ok(OperationResult::RolesUpdated)
+            }
+
+            Operation::RevokeRole { member, role } => {
+                // Only admin can manage roles
+                self.require_role(&owner, Role::Admin).await?;
+
+                // Never leave the application without an admin
+                if role == Role::Admin {
+                    let admins = self.state.get_admins().await;
+                    if admins.len() <= 1 && admins.contains(&member) {
+                        return Err(NexusError::LastAdmin);
+                    }
+                }
+
+                self.state.revoke_role(&member, role, &owner, now_seconds as u64).await;
+                Ok(OperationResult::RolesUpdated)
+            }
+
+            Operation::TransferAdmin { new_admin } => {
+                // Only an admin can hand over its own admin role
+                self.require_role(&owner, Role::Admin).await?;
+                if new_admin == owner {
+                    return Err(NexusError::CannotTransferToSelf);
+                }
+
+                // Grant first so the application always keeps an admin
+                self.state.grant_role(&new_admin, Role::Admin, &owner, now_seconds as u64).await;
+                self.state.revoke_role(&owner, Role::Admin, &owner, now_seconds as u64).await;
+                Ok(OperationResult::RolesUpdated)
             }
         }
     }
 
-    async fn execute_message(&mut self, _message: Self::Message) {
-        // No cross-chain messages supported yet
+    /// Fail unless the caller holds the given role.
+    async fn require_role(&self, owner: &str, role: Role) -> Result<(), NexusError> {
+        if self.state.has_role(owner, role).await {
+            Ok(())
+        } else {
+            Err(NexusError::Unauthorized)
+        }
     }
 
-    async fn store(mut self) {
-        self.state.save().await.expect("Failed to save state");
+    /// Get a season that is currently live.
+    async fn live_season(&self, season_id: u64, now_seconds: i64) -> Result<Season, NexusError> {
+        match self.state.get_season(season_id).await {
+            Some(s) if s.phase(now_seconds) == SeasonPhase::Live => Ok(s),
+            Some(s) if s.active => Err(NexusError::OutsideSeasonWindow),
+            Some(_) => Err(NexusError::SeasonInactive),
+            None => Err(NexusError::SeasonNotFound),
+        }
+    }
+
+    /// Add a deposit that reached the application's account to a season's prize pool.
+    async fn fund_prize_pool(&mut self, season_id: u64, amount: Amount) -> Result<Amount, NexusError> {
+        let mut season = match self.state.get_season(season_id).await {
+            Some(s) if !s.archived => s,
+            Some(_) => return Err(NexusError::SeasonArchived),
+            None => return Err(NexusError::SeasonNotFound),
+        };
+        season.prize_pool = season.prize_pool.saturating_add(amount);
+        let prize_pool = season.prize_pool;
+        self.state.save_season(season).await;
+        Ok(prize_pool)
+    }
+
+    /// Credit XP reported by a game to a player, if the game is registered.
+    async fn record_game_xp(
+        &mut self,
+        game: ApplicationId,
+        season_id: u64,
+        player: &str,
+        points: u64,
+        now_seconds: i64,
+    ) -> Result<(), NexusError> {
+        // Check the reporting game is whitelisted
+        let registered = self
+            .state
+            .get_registered_game(&game)
+            .await
+            .ok_or(NexusError::UnknownGame)?;
+
+        // Check season exists and is live
+        let season = self.live_season(season_id, now_seconds).await?;
+
+        // Enforce the season's anti-abuse limits
+        self.check_action_limits(player, &season, registered.category, points, true, now_seconds)
+            .await?;
+
+        // Get or create player stats
+        self.state
+            .log_activity(ActivityKind::XpGranted, season_id, Some(player), points, None, Some(game), now_seconds)
+            .await;
+        let mut stats = self.state.get_player_stats(player, season_id).await;
+        let before = stats.clone();
+        stats.add_xp(registered.category, points);
+        stats.last_active_at = now_seconds as u64;
+        let bonus = self.advance_quest_progress(&season, &before, &mut stats, now_seconds).await;
+        self.credit_guild_xp(player, season_id, points + bonus).await;
+        self.refresh_skill_index(&stats, now_seconds).await?;
+        self.state.save_player_stats(stats).await;
+        Ok(())
+    }
+
+    /// Apply a game event attested by a registered game to a player's stats.
+    async fn record_game_event(
+        &mut self,
+        game: ApplicationId,
+        season_id: u64,
+        player: &str,
+        kind: GameEventKind,
+        value: u64,
+        now_seconds: i64,
+    ) -> Result<(), NexusError> {
+        // Check the reporting game is whitelisted
+        let registered = self
+            .state
+            .get_registered_game(&game)
+            .await
+            .ok_or(NexusError::UnknownGame)?;
+
+        // A game may only attest events of its own category
+        if registered.category != kind.category() && registered.category != QuestCategory::Mixed {
+            return Err(NexusError::WrongEventCategory);
+        }
+
+        // Check season exists and is live
+        let season = self.live_season(season_id, now_seconds).await?;
+
+        let mut stats = self.state.get_player_stats(player, season_id).await;
+        let before = stats.clone();
+        stats.apply_event(kind, value);
+        let bonus = self.advance_quest_progress(&season, &before, &mut stats, now_seconds).await;
+        if bonus > 0 {
+            stats.last_active_at = now_seconds as u64;
+            self.credit_guild_xp(player, season_id, bonus).await;
+            self.refresh_skill_index(&stats, now_seconds).await?;
+        }
+        self.state.save_player_stats(stats).await;
+        Ok(())
+    }
+
+    /// Update the player's progress on the open quests whose metric changed
+    /// since `before`, paying the XP of newly reached milestones.
+    /// Returns the milestone XP added to `stats`.
+    async fn advance_quest_progress(
+        &mut self,
+        season: &Season,
+        before: &PlayerSeasonStats,
+        stats: &mut PlayerSeasonStats,
+        now_seconds: i64,
+    ) -> u64 {
+        let mut bonus = 0;
+        // Event counters first, then game scores, then total XP, so that the
+        // XP of milestones paid along the way is seen by later metrics
+        for metric in RequirementMetric::ALL.iter().rev().copied() {
+            if stats.metric_value(metric) == before.metric_value(metric) {
+                continue;
+            }
+            for quest in self.state.get_metric_quests(stats.season_id, metric).await {
+                bonus += self.advance_quest(season, &quest, metric, stats, now_seconds).await;
+            }
+        }
+        bonus
+    }
+
+    /// Update the player's progress on one quest, paying the XP of newly
+    /// reached milestones. Milestones are gated like `CompleteQuest`: the
+    /// quest must be open, have completion slots left and be unlocked, and
+    /// their XP counts towards the season's daily caps. A milestone over the
+    /// cap stays unpaid until a later update fits it.
+    /// Returns the milestone XP added to `stats`.
+    async fn advance_quest(
+        &mut self,
+        season: &Season,
+        quest: &Quest,
+        metric: RequirementMetric,
+        stats: &mut PlayerSeasonStats,
+        now_seconds: i64,
+    ) -> u64 {
+        if !quest.active || !quest.is_open(now_seconds) || quest.is_full() {
+            return 0;
+        }
+        if let Some(prerequisite) = quest.prerequisite {
+            if !self.state.get_quest_progress(&stats.owner, prerequisite).await.completed {
+                return 0;
+            }
+        }
+
+        let mut progress = self.state.get_quest_progress(&stats.owner, quest.id).await;
+        let current = stats.metric_value(metric);
+        if current == progress.progress {
+            return 0;
+        }
+        progress.progress = current;
+
+        // Pay each milestone once, in order
+        let mut bonus = 0;
+        for milestone in quest.milestones.iter().skip(progress.milestones_reached as usize) {
+            if current < milestone.target {
+                break;
+            }
+            if !self
+                .take_daily_xp(&stats.owner, season, quest.category, milestone.reward_xp, now_seconds)
+                .await
+            {
+                break;
+            }
+            stats.add_xp(quest.category, milestone.reward_xp);
+            self.state
+                .log_activity(
+                    ActivityKind::MilestoneReached,
+                    stats.season_id,
+                    Some(&stats.owner),
+                    milestone.reward_xp,
+                    Some(quest.id),
+                    None,
+                    now_seconds,
+                )
+                .await;
+            bonus += milestone.reward_xp;
+            progress.milestones_reached += 1;
+        }
+        self.state.save_quest_progress(progress).await;
+        bonus
+    }
+
+    /// Check an XP-earning action against the season's anti-abuse limits and
+    /// record it if accepted. Rejections are counted for moderation.
+    async fn check_action_limits(
+        &mut self,
+        player: &str,
+        season: &Season,
+        category: QuestCategory,
+        points: u64,
+        game_action: bool,
+        now_seconds: i64,
+    ) -> Result<(), NexusError> {
+        let limits = &season.limits;
+        let day = (now_seconds / 86_400) as u64;
+
+        // Daily counters reset at the start of each UTC day
+        let mut activity = self.state.get_player_activity(player, season.id).await;
+        activity.start_day(day);
+
+        let too_frequent = limits
+            .min_action_interval
+            .zip(activity.last_action_at)
+            .is_some_and(|(interval, last)| now_seconds < last + interval);
+        let too_many_points = game_action && limits.max_points_per_action.is_some_and(|max| points > max);
+        let over_cap = !limits.within_daily_cap(&activity, category, points);
+
+        let violation = if too_frequent {
+            Some(LimitViolation::TooFrequent)
+        } else if too_many_points {
+            Some(LimitViolation::TooManyPoints)
+        } else if over_cap {
+            Some(LimitViolation::DailyCapReached)
+        } else {
+            None
+        };
+
+        if let Some(violation) = violation {
+            let mut record = self.state.get_moderation_record(player, season.id).await;
+            record.record(violation, now_seconds);
+            self.state.save_moderation_record(record).await;
+            return Err(NexusError::RateLimited(violation));
+        }
+
+        activity.add_xp_today(category, points);
+        activity.last_action_at = Some(now_seconds);
+        self.state.save_player_activity(activity).await;
+        Ok(())
+    }
+
+    /// Count bonus XP towards the player's daily cap in a category, if it
+    /// fits. Unlike actions, bonuses are not rate limited or moderated.
+    async fn take_daily_xp(
+        &mut self,
+        player: &str,
+        season: &Season,
+        category: QuestCategory,
+        points: u64,
+        now_seconds: i64,
+    ) -> bool {
+        let mut activity = self.state.get_player_activity(player, season.id).await;
+        activity.start_day((now_seconds / 86_400) as u64);
+        if !season.limits.within_daily_cap(&activity, category, points) {
+            return false;
+        }
+        activity.add_xp_today(category, points);
+        self.state.save_player_activity(activity).await;
+        true
+    }
+
+    /// The account owner under which the application holds prize pools.
+    fn application_owner(&mut self) -> AccountOwner {
+        AccountOwner::from(self.runtime.application_id().forget_abi())
+    }
+
+    /// Close a season and snapshot the final ranks of its first batch of
+    /// players. Returns the number of players still to be snapshotted.
+    async fn finalize_season(&mut self, mut season: Season, now_seconds: i64) -> u32 {
+        let season_id = season.id;
+        season.active = false;
+        season.finalized_at = Some(now_seconds as u64);
+        season.ranked_players = self.state.get_ranked_count(season_id).await;
+        season.scale_payouts_to_prize_pool();
+        season.previous_season = self.state.get_last_finalized_season(&season.theme).await;
+        self.state.set_last_finalized_season(&season.theme, season_id).await;
+        self.state
+            .log_activity(ActivityKind::SeasonFinalized, season_id, None, 0, None, None, now_seconds)
+            .await;
+
+        self.snapshot_ranks(season, now_seconds).await
+    }
+
+    /// Snapshot the final rank of the next `MAX_FINALIZE_BATCH` players of a
+    /// finalized season and fold the season into their careers. XP no longer
+    /// changes once a season is inactive, so the leaderboard order is stable
+    /// between batches. Returns the number of players still to be snapshotted.
+    async fn snapshot_ranks(&mut self, mut season: Season, now_seconds: i64) -> u32 {
+        let batch = self
+            .state
+            .get_leaderboard_page(season.id, season.snapshot_cursor.as_deref(), MAX_FINALIZE_BATCH)
+            .await
+            .unwrap_or_default();
+        if batch.is_empty() {
+            // Nothing left to walk, whatever the count said
+            season.ranks_snapshotted = season.ranked_players;
+        }
+
+        for mut stats in batch {
+            let rank = season.ranks_snapshotted + 1;
+            let mut career = self.state.get_career(&stats.owner).await;
+            career.record_season(&stats, rank, &season.theme, season.previous_season, now_seconds as u64);
+            self.state.save_career(career).await;
+
+            season.snapshot_cursor = Some(ArcadeNexusState::encode_cursor(&stats));
+            season.ranks_snapshotted = rank;
+            stats.rank_snapshot = Some(rank);
+            self.state.save_player_stats(stats).await;
+        }
+
+        let ranks_pending = season.ranked_players.saturating_sub(season.ranks_snapshotted);
+        self.state.save_season(season).await;
+        ranks_pending
+    }
+
+    /// Create the next season of a theme from a rollover template, carrying
+    /// over the previous season's reward tiers, skill formula and limits.
+    /// Returns the new season's ID, or None if the slot is already taken.
+    async fn create_rollover_season(
+        &mut self,
+        template: SeasonTemplate,
+        previous: &Season,
+        now_seconds: i64,
+    ) -> Option<u64> {
+        let start_time = previous.end_time + template.gap;
+        let end_time = start_time + template.duration;
+        if self.state.has_overlapping_season(&previous.theme, start_time, end_time).await {
+            return None; // Next season already scheduled
+        }
+
+        let season_id = self.state.get_next_season_id().await;
+        let season = Season {
+            id: season_id,
+            title: template.title.clone(),
+            description: template.description.clone(),
+            start_time,
+            end_time,
+            active: true,
+            theme: previous.theme.clone(),
+            grace_period: template.grace_period,
+            finalized_at: None,
+            archived: false,
+            rollover: Some(template),
+            reward_tiers: previous.reward_tiers.clone(),
+            prize_pool: Amount::ZERO,
+            ranked_players: 0,
+            payout_ppm: FULL_PAYOUT_PPM,
+            ranks_snapshotted: 0,
+            snapshot_cursor: None,
+            previous_season: None,
+            skill_config: previous.skill_config.clone(),
+            limits: previous.limits.clone(),
+        };
+        self.state.save_season(season).await;
+        self.state
+            .log_activity(ActivityKind::SeasonCreated, season_id, None, 0, None, None, now_seconds)
+            .await;
+        Some(season_id)
+    }
+
+    /// Recompute and persist a player's skill index with the season's formula.
+    async fn refresh_skill_index(
+        &mut self,
+        stats: &PlayerSeasonStats,
+        now_seconds: i64,
+    ) -> Result<ArcadeSkillIndex, NexusError> {
+        let config = self
+            .state
+            .get_season(stats.season_id)
+            .await
+            .ok_or(NexusError::SeasonNotFound)?
+            .skill_config;
+
+        let overall_score = config.score(stats, now_seconds);
+        let index = ArcadeSkillIndex {
+            owner: stats.owner.clone(),
+            season_id: stats.season_id,
+            total_xp: stats.total_xp,
+            overall_score,
+            rank_hint: config.tier_for(overall_score),
+            computed_at: now_seconds as u64,
+        };
+        self.state.save_skill_index(index.clone()).await;
+        Ok(index)
+    }
+
+    /// Add XP earned by a player to their guild's season total, if they are in one.
+    async fn credit_guild_xp(&mut self, player: &str, season_id: u64, points: u64) {
+        let guild_id = match self.state.get_guild_id_of(player).await {
+            Some(id) => id,
+            None => return, // Not in a guild
+        };
+
+        let mut stats = self.state.get_guild_season_stats(guild_id, season_id).await;
+        stats.total_xp += points;
+        self.state.save_guild_season_stats(stats).await;
+    }
+
+    /// Add a player's XP in every open season to a guild's totals when they
+    /// join it, or take it back out when they leave. Finalized seasons keep
+    /// their guild standings.
+    async fn move_guild_member_xp(&mut self, player: &str, guild_id: u64, joining: bool) {
+        for season in self.state.get_active_seasons().await {
+            if !self.state.has_player_stats(player, season.id).await {
+                continue;
+            }
+            let xp = self.state.get_player_stats(player, season.id).await.total_xp;
+            let mut stats = self.state.get_guild_season_stats(guild_id, season.id).await;
+            stats.total_xp = if joining {
+                stats.total_xp + xp
+            } else {
+                stats.total_xp.saturating_sub(xp)
+            };
+            self.state.save_guild_season_stats(stats).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arcade_nexus::{NexusError, Operation, OperationResult, PlayerSeasonStats, QuestCategory, Role};
+    use linera_sdk::{
+        linera_base_types::{AccountOwner, ApplicationId, ChainId, CryptoHash, Timestamp},
+        util::BlockingWait,
+        views::View,
+        Contract, ContractRuntime,
+    };
+
+    use super::{ArcadeNexusContract, ArcadeNexusState};
+
+    fn owner(name: &str) -> AccountOwner {
+        AccountOwner::from(CryptoHash::test_hash(name))
+    }
+
+    fn game() -> ApplicationId {
+        ApplicationId::new(CryptoHash::test_hash("game"))
+    }
+
+    /// Instantiate the contract on its creator chain, with `admin` as the creator
+    fn create_contract(admin: AccountOwner) -> ArcadeNexusContract {
+        let chain_id = ChainId(CryptoHash::test_hash("hub"));
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(())
+            .with_chain_id(chain_id)
+            .with_application_creator_chain_id(chain_id)
+            .with_authenticated_signer(admin)
+            .with_authenticated_caller_id(None)
+            .with_system_time(Timestamp::from(100_000_000));
+        let mut contract = ArcadeNexusContract {
+            state: ArcadeNexusState::load(runtime.root_view_storage_context())
+                .blocking_wait()
+                .expect("Failed to read from mock key value store"),
+            runtime,
+        };
+        contract.instantiate(()).blocking_wait();
+        contract
+    }
+
+    fn register_game(contract: &mut ArcadeNexusContract) -> OperationResult {
+        contract
+            .execute_operation(Operation::RegisterGame {
+                application_id: game(),
+                name: "Typing Arena".to_string(),
+                category: QuestCategory::Typing,
+            })
+            .blocking_wait()
+    }
+
+    /// Create a season live from 0 to 1000 seconds, with defaults for everything else
+    fn create_season(contract: &mut ArcadeNexusContract) -> OperationResult {
+        contract
+            .execute_operation(Operation::CreateSeason {
+                title: "Season 1".to_string(),
+                description: String::new(),
+                start_time: 0,
+                end_time: 1_000,
+                theme: None,
+                grace_period: None,
+                rollover: None,
+                reward_tiers: None,
+                skill_config: None,
+                limits: None,
+            })
+            .blocking_wait()
+    }
+
+    fn create_live_season(contract: &mut ArcadeNexusContract) -> u64 {
+        match create_season(contract) {
+            OperationResult::SeasonCreated { season_id } => season_id,
+            other => panic!("Season was not created: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn users_cannot_record_game_actions() {
+        let player = owner("player");
+        let mut contract = create_contract(player);
+
+        let result = contract
+            .execute_operation(Operation::RecordGameAction { season_id: None, points: 10 })
+            .blocking_wait();
+        assert!(matches!(result, OperationResult::Error(NexusError::NotCalledByApplication)));
+    }
+
+    #[test]
+    fn unregistered_games_cannot_record_game_actions() {
+        let admin = owner("admin");
+        let mut contract = create_contract(admin);
+        let season_id = create_live_season(&mut contract);
+
+        contract.runtime.set_authenticated_caller_id(Some(game()));
+        let result = contract
+            .execute_operation(Operation::RecordGameAction { season_id: Some(season_id), points: 10 })
+            .blocking_wait();
+        assert!(matches!(result, OperationResult::Error(NexusError::UnknownGame)));
+    }
+
+    #[test]
+    fn registered_games_credit_the_signer() {
+        let admin = owner("admin");
+        let mut contract = create_contract(admin);
+        let season_id = create_live_season(&mut contract);
+        assert!(matches!(register_game(&mut contract), OperationResult::RegistryUpdated));
+
+        let player = owner("player");
+        contract.runtime.set_authenticated_signer(Some(player));
+        contract.runtime.set_authenticated_caller_id(Some(game()));
+        let result = contract
+            .execute_operation(Operation::RecordGameAction { season_id: Some(season_id), points: 10 })
+            .blocking_wait();
+        assert!(matches!(result, OperationResult::ActionRecorded));
+
+        let stats = contract
+            .state
+            .get_player_stats(&player.to_string(), season_id)
+            .blocking_wait();
+        assert_eq!(stats.total_xp, 10);
+        assert_eq!(stats.typing_score, 10);
+    }
+
+    #[test]
+    fn only_admins_manage_the_game_registry() {
+        let admin = owner("admin");
+        let mut contract = create_contract(admin);
+
+        contract.runtime.set_authenticated_signer(Some(owner("player")));
+        assert!(matches!(
+            register_game(&mut contract),
+            OperationResult::Error(NexusError::Unauthorized)
+        ));
+        assert!(contract.state.get_registered_game(&game()).blocking_wait().is_none());
+
+        contract.runtime.set_authenticated_signer(Some(admin));
+        assert!(matches!(register_game(&mut contract), OperationResult::RegistryUpdated));
+        assert!(contract.state.get_registered_game(&game()).blocking_wait().is_some());
+    }
+
+    #[test]
+    fn only_admins_grant_roles() {
+        let admin = owner("admin");
+        let player = owner("player");
+        let mut contract = create_contract(admin);
+        let grant = Operation::GrantRole {
+            member: player.to_string(),
+            role: Role::Admin,
+        };
+
+        // Nobody can grant themselves a role
+        contract.runtime.set_authenticated_signer(Some(player));
+        let result = contract.execute_operation(grant.clone()).blocking_wait();
+        assert!(matches!(result, OperationResult::Error(NexusError::Unauthorized)));
+        assert!(!contract.state.has_role(&player.to_string(), Role::Admin).blocking_wait());
+
+        contract.runtime.set_authenticated_signer(Some(admin));
+        let result = contract.execute_operation(grant).blocking_wait();
+        assert!(!matches!(result, OperationResult::Error(_)));
+        assert!(contract.state.has_role(&player.to_string(), Role::Admin).blocking_wait());
+    }
+
+    #[test]
+    fn only_season_managers_create_seasons() {
+        let admin = owner("admin");
+        let mut contract = create_contract(admin);
+
+        contract.runtime.set_authenticated_signer(Some(owner("player")));
+        assert!(matches!(
+            create_season(&mut contract),
+            OperationResult::Error(NexusError::Unauthorized)
+        ));
+    }
+
+    fn save_xp(contract: &mut ArcadeNexusContract, owner: &str, total_xp: u64) {
+        let stats = PlayerSeasonStats {
+            owner: owner.to_string(),
+            season_id: 0,
+            total_xp,
+            ..PlayerSeasonStats::default()
+        };
+        contract.state.save_player_stats(stats).blocking_wait();
+    }
+
+    fn season_rank(contract: &ArcadeNexusContract, owner: &str) -> Option<u32> {
+        contract.state.get_player_rank(owner, 0).blocking_wait()
+    }
+
+    #[test]
+    fn season_ranks_count_ties_and_byte_boundaries() {
+        let mut contract = create_contract(owner("admin"));
+        // Inverted big-endian, 256 and 255 differ in the second to last byte
+        for (owner, xp) in [("d", 255), ("b", 256), ("a", 256), ("c", 70_000), ("e", 0)] {
+            save_xp(&mut contract, owner, xp);
+        }
+
+        assert_eq!(contract.state.get_ranked_count(0).blocking_wait(), 5);
+        let ranks = ["c", "a", "b", "d", "e"].map(|owner| season_rank(&contract, owner));
+        assert_eq!(ranks, [Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        assert_eq!(season_rank(&contract, "nobody"), None);
+
+        let first = contract.state.get_leaderboard_page(0, None, 2).blocking_wait().unwrap();
+        let cursor = ArcadeNexusState::encode_cursor(first.last().unwrap());
+        let rest = contract.state.get_leaderboard_page(0, Some(&cursor), 10).blocking_wait().unwrap();
+        let owners = first.iter().chain(&rest).map(|stats| stats.owner.as_str()).collect::<Vec<_>>();
+        assert_eq!(owners, ["c", "a", "b", "d", "e"]);
+    }
+
+    #[test]
+    fn season_ranks_follow_xp_updates() {
+        let mut contract = create_contract(owner("admin"));
+        save_xp(&mut contract, "a", 300);
+        save_xp(&mut contract, "b", 200);
+        save_xp(&mut contract, "c", 100);
+
+        save_xp(&mut contract, "c", 1_000);
+        let ranks = ["c", "a", "b"].map(|owner| season_rank(&contract, owner));
+        assert_eq!(ranks, [Some(1), Some(2), Some(3)]);
+
+        save_xp(&mut contract, "c", 100);
+        let ranks = ["a", "b", "c"].map(|owner| season_rank(&contract, owner));
+        assert_eq!(ranks, [Some(1), Some(2), Some(3)]);
+        assert_eq!(contract.state.get_ranked_count(0).blocking_wait(), 3);
+
+        let around = contract.state.get_players_around("b", 0, 1).blocking_wait();
+        let around = around.iter().map(|(rank, stats)| (*rank, stats.owner.as_str())).collect::<Vec<_>>();
+        assert_eq!(around, [(1, "a"), (2, "b"), (3, "c")]);
+    }
+
+    fn guild_xp(contract: &ArcadeNexusContract, guild_id: u64, season_id: u64) -> u64 {
+        contract.state.get_guild_season_stats(guild_id, season_id).blocking_wait().total_xp
+    }
+
+    #[test]
+    fn guilds_pool_the_season_xp_of_their_current_members() {
+        let mut contract = create_contract(owner("admin"));
+        let season_id = create_live_season(&mut contract);
+        let (leader, member) = (owner("leader"), owner("member"));
+        for (player, total_xp) in [(leader, 300), (member, 200)] {
+            let stats = PlayerSeasonStats {
+                owner: player.to_string(),
+                season_id,
+                total_xp,
+                ..PlayerSeasonStats::default()
+            };
+            contract.state.save_player_stats(stats).blocking_wait();
+        }
+
+        contract.runtime.set_authenticated_signer(Some(leader));
+        let guild_id = match contract
+            .execute_operation(Operation::CreateGuild {
+                name: "Guild".to_string(),
+                max_members: None,
+                invite_only: false,
+            })
+            .blocking_wait()
+        {
+            OperationResult::GuildCreated { guild_id } => guild_id,
+            other => panic!("Guild was not created: {other:?}"),
+        };
+        assert_eq!(guild_xp(&contract, guild_id, season_id), 300);
+
+        contract.runtime.set_authenticated_signer(Some(member));
+        let result = contract.execute_operation(Operation::JoinGuild { guild_id }).blocking_wait();
+        assert!(matches!(result, OperationResult::GuildUpdated));
+        assert_eq!(guild_xp(&contract, guild_id, season_id), 500);
+
+        let result = contract.execute_operation(Operation::LeaveGuild).blocking_wait();
+        assert!(matches!(result, OperationResult::GuildUpdated));
+        assert_eq!(guild_xp(&contract, guild_id, season_id), 300);
+
+        contract.execute_operation(Operation::JoinGuild { guild_id }).blocking_wait();
+        contract.runtime.set_authenticated_signer(Some(leader));
+        let result = contract
+            .execute_operation(Operation::KickFromGuild { player: member.to_string() })
+            .blocking_wait();
+        assert!(matches!(result, OperationResult::GuildUpdated));
+        assert_eq!(guild_xp(&contract, guild_id, season_id), 300);
+    }
+
+    #[test]
+    fn season_metric_totals_follow_stats_updates() {
+        let mut contract = create_contract(owner("admin"));
+        save_xp(&mut contract, "a", 300);
+        save_xp(&mut contract, "b", 200);
+        save_xp(&mut contract, "a", 100);
+
+        // Columns follow `RequirementMetric::ALL`, total XP first
+        let totals = contract.state.get_metric_totals(0).blocking_wait();
+        assert_eq!(totals[0], 300);
+        assert!(totals[1..].iter().all(|total| *total == 0));
+        assert_eq!(contract.state.get_metric_totals(1).blocking_wait(), [0; 9]);
     }
 }
@@ -1,15 +1,24 @@
 //! Arcade Nexus - Cross-Game Reputation & Live-Ops Layer for Linera Arcade Hub
 //!
 //! This contract provides:
-//! - Seasons with time-limited XP tracking
-//! - Cross-game leaderboards
-//! - Dynamic quests with XP rewards
-//! - Player skill indexing across all games
+//! - Seasons with time-limited XP tracking, a derived lifecycle and auto-rollover
+//! - Cross-game leaderboards with a maintained per-season ranking index
+//! - End-of-season rewards paid in native tokens from a per-season prize pool
+//! - Dynamic quests with XP rewards, machine-checkable requirements and milestones
+//! - Quest templates filled in batches by off-chain generators, with provenance
+//! - Player skill indexing across all games with per-season weights, tiers and decay
+//! - Lifetime career records and achievements awarded when seasons close
+//! - Guilds with invites, member limits and a season guild leaderboard
+//! - A registry of trusted game applications allowed to report XP
+//! - Role-based access control for admins, season managers and quest authors
+//! - Per-season anti-abuse rate limits and daily XP caps with moderation counters
+//! - An append-only activity log with player timelines and daily season XP
 //!
 //! Designed to be AI-friendly for off-chain quest generation.
 
-use async_graphql::SimpleObject;
+use async_graphql::{InputObject, InputType, SimpleObject};
 use linera_sdk::graphql::GraphQLMutationRoot;
+use linera_sdk::linera_base_types::{Amount, ApplicationId};
 use serde::{Deserialize, Serialize};
 
 /// Application binary interface for ArcadeNexus.
@@ -17,7 +26,7 @@ pub struct ArcadeNexusAbi;
 
 impl linera_sdk::linera_base_types::ContractAbi for ArcadeNexusAbi {
     type Operation = Operation;
-    type Response = ();
+    type Response = OperationResult;
 }
 
 impl linera_sdk::linera_base_types::ServiceAbi for ArcadeNexusAbi {
@@ -48,6 +57,454 @@ impl Default for QuestCategory {
     }
 }
 
+/// A game event attested by a registered game application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum GameEventKind {
+    /// Won a Prediction Pulse round (value = rounds won)
+    PredictionWin,
+    /// Voted in a Meme Battle match (value = matches voted in)
+    MemeBattleVote,
+    /// Completed a Typing Arena challenge (value = challenges completed)
+    TypingChallengeCompleted,
+    /// Reached a Game of Life generation (value = generation reached)
+    LifeGenerationReached,
+}
+
+impl GameEventKind {
+    /// Category of the game allowed to attest this event.
+    pub fn category(&self) -> QuestCategory {
+        match self {
+            GameEventKind::PredictionWin => QuestCategory::Prediction,
+            GameEventKind::MemeBattleVote => QuestCategory::Meme,
+            GameEventKind::TypingChallengeCompleted => QuestCategory::Typing,
+            GameEventKind::LifeGenerationReached => QuestCategory::Life,
+        }
+    }
+}
+
+/// The statistic a quest requirement is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum RequirementMetric {
+    /// Total season XP
+    TotalXp,
+    /// Prediction Pulse XP
+    PredictionScore,
+    /// Meme Auction XP
+    MemeScore,
+    /// Typing Arena XP
+    TypingScore,
+    /// Game of Life XP
+    LifeScore,
+    /// Prediction rounds won
+    PredictionWins,
+    /// Meme Battle matches voted in
+    MemeBattleVotes,
+    /// Typing challenges completed
+    TypingChallenges,
+    /// Highest Game of Life generation reached
+    LifeGeneration,
+}
+
+impl RequirementMetric {
+    /// Every metric, in the column order of `SeasonExport` rows.
+    pub const ALL: [RequirementMetric; 9] = [
+        RequirementMetric::TotalXp,
+        RequirementMetric::PredictionScore,
+        RequirementMetric::MemeScore,
+        RequirementMetric::TypingScore,
+        RequirementMetric::LifeScore,
+        RequirementMetric::PredictionWins,
+        RequirementMetric::MemeBattleVotes,
+        RequirementMetric::TypingChallenges,
+        RequirementMetric::LifeGeneration,
+    ];
+}
+
+/// A machine-checkable quest requirement: `metric` must reach `target`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "QuestRequirementInput")]
+pub struct QuestRequirement {
+    /// Statistic to check
+    pub metric: RequirementMetric,
+    /// Minimum value required
+    pub target: u64,
+}
+
+impl QuestRequirement {
+    /// Whether the given season stats gained `target` on the metric since it
+    /// stood at `baseline` (0 before a quest's first completion).
+    pub fn is_met(&self, stats: &PlayerSeasonStats, baseline: u64) -> bool {
+        stats.metric_value(self.metric).saturating_sub(baseline) >= self.target
+    }
+}
+
+/// New value of an optional field in an edit: `value: None` clears it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, InputObject)]
+#[graphql(
+    concrete(name = "ClearableI64", params(i64)),
+    concrete(name = "ClearableU32", params(u32)),
+    concrete(name = "ClearableRequirement", params(QuestRequirement))
+)]
+pub struct Clearable<T: InputType> {
+    pub value: Option<T>,
+}
+
+/// An intermediate step of a quest that pays partial XP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "QuestMilestoneInput")]
+pub struct QuestMilestone {
+    /// Value of the quest requirement's metric that reaches this milestone
+    pub target: u64,
+    /// XP awarded when the milestone is reached
+    pub reward_xp: u64,
+}
+
+/// A privilege that can be granted to an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum Role {
+    /// Full control, including role management and the game registry.
+    /// Admins implicitly hold every other role.
+    Admin,
+    /// Can create and close seasons
+    SeasonManager,
+    /// Can create quests
+    QuestAuthor,
+}
+
+/// The roles held by a single account.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RoleMember {
+    /// Member's account owner
+    pub member: String,
+    /// Roles explicitly granted to this member
+    pub roles: Vec<Role>,
+    /// Account owner that last changed this member's roles
+    pub updated_by: String,
+    /// Last change timestamp in seconds since epoch
+    pub updated_at: u64,
+}
+
+impl RoleMember {
+    /// Whether this member holds the given role, directly or through Admin.
+    pub fn has_role(&self, role: Role) -> bool {
+        self.roles.contains(&Role::Admin) || self.roles.contains(&role)
+    }
+}
+
+/// Lifecycle phase of a season, derived from its times and flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum SeasonPhase {
+    /// Created but not started yet
+    Scheduled,
+    /// Running; games may report XP
+    Live,
+    /// Ended; quests may still be claimed until the grace period is over
+    Grace,
+    /// Over; ranks are snapshotted once finalized
+    Closed,
+    /// Closed and archived
+    Archived,
+}
+
+/// Template used to automatically create the next season on finalization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "SeasonTemplateInput")]
+pub struct SeasonTemplate {
+    /// Title of the next season
+    pub title: String,
+    /// Description of the next season
+    pub description: String,
+    /// Length of the next season in seconds
+    pub duration: i64,
+    /// Grace period of the next season in seconds
+    pub grace_period: i64,
+    /// Pause between the end of this season and the start of the next, in seconds
+    pub gap: i64,
+}
+
+/// A reward bracket paid out when a season is finalized.
+///
+/// A player falls into the first tier whose `max_rank` or `max_percentile`
+/// covers their final rank.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "RewardTierInput")]
+pub struct RewardTier {
+    /// Covers ranks 1..=max_rank (e.g. 10 for "top 10")
+    pub max_rank: Option<u32>,
+    /// Covers the top max_percentile percent of ranked players
+    pub max_percentile: Option<u32>,
+    /// Amount paid to each player in this tier
+    pub amount: Amount,
+}
+
+impl RewardTier {
+    /// Whether this tier covers the given rank out of `ranked_players`.
+    pub fn covers(&self, rank: u32, ranked_players: u32) -> bool {
+        let by_rank = self.max_rank.is_some_and(|max| rank <= max);
+        let by_percentile = self
+            .max_percentile
+            .is_some_and(|pct| u64::from(rank) * 100 <= u64::from(pct) * u64::from(ranked_players));
+        by_rank || by_percentile
+    }
+
+    /// Highest rank this tier covers out of `ranked_players`.
+    pub fn last_rank(&self, ranked_players: u32) -> u32 {
+        let by_rank = self.max_rank.unwrap_or(0);
+        let by_percentile = self
+            .max_percentile
+            .map_or(0, |pct| (u64::from(pct) * u64::from(ranked_players) / 100) as u32);
+        by_rank.max(by_percentile).min(ranked_players)
+    }
+
+    /// Whether the tier has a bound and the bounds are in range.
+    pub fn is_valid(&self) -> bool {
+        (self.max_rank.is_some() || self.max_percentile.is_some())
+            && self.max_rank != Some(0)
+            && self.max_percentile.is_none_or(|pct| (1..=100).contains(&pct))
+    }
+}
+
+/// A season reward that has been paid out to a player.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RewardClaim {
+    /// Player's account owner
+    pub owner: String,
+    /// Season ID
+    pub season_id: u64,
+    /// Final rank the reward was computed from
+    pub rank: u32,
+    /// Amount transferred
+    pub amount: Amount,
+    /// Claim timestamp in seconds since epoch
+    pub claimed_at: u64,
+}
+
+/// A named skill tier reached at a minimum overall score.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "SkillTierInput")]
+pub struct SkillTier {
+    /// Tier name (e.g., "Gold")
+    pub name: String,
+    /// Minimum overall score for this tier
+    pub min_score: u64,
+}
+
+/// Per-season formula for the Arcade Skill Index.
+///
+/// Weights are percentages: 100 counts a category's XP once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "SkillIndexConfigInput")]
+pub struct SkillIndexConfig {
+    /// Weight of Prediction Pulse XP
+    pub prediction_weight: u32,
+    /// Weight of Meme Auction XP
+    pub meme_weight: u32,
+    /// Weight of Typing Arena XP
+    pub typing_weight: u32,
+    /// Weight of Game of Life XP
+    pub life_weight: u32,
+    /// Weight of XP not attributed to a single game (mixed/other)
+    pub other_weight: u32,
+    /// Tiers, any order; the highest one reached is used
+    pub tiers: Vec<SkillTier>,
+    /// Seconds of inactivity after which the score is halved (None = no decay)
+    pub decay_half_life: Option<i64>,
+}
+
+impl Default for SkillIndexConfig {
+    fn default() -> Self {
+        let tier = |name: &str, min_score| SkillTier {
+            name: name.to_string(),
+            min_score,
+        };
+        SkillIndexConfig {
+            prediction_weight: 100,
+            meme_weight: 100,
+            typing_weight: 100,
+            life_weight: 100,
+            other_weight: 100,
+            tiers: vec![
+                tier("Bronze", 0),
+                tier("Silver", 1000),
+                tier("Gold", 5000),
+                tier("Legendary", 10000),
+            ],
+            decay_half_life: None,
+        }
+    }
+}
+
+impl SkillIndexConfig {
+    /// Weighted score of a player's stats, decayed by time since their last activity.
+    pub fn score(&self, stats: &PlayerSeasonStats, now_seconds: i64) -> u64 {
+        let weighted = |xp: u64, weight: u32| xp.saturating_mul(u64::from(weight)) / 100;
+        let categorized = stats.prediction_score + stats.meme_score + stats.typing_score + stats.life_score;
+        let other = stats.total_xp.saturating_sub(categorized);
+        let score = weighted(stats.prediction_score, self.prediction_weight)
+            + weighted(stats.meme_score, self.meme_weight)
+            + weighted(stats.typing_score, self.typing_weight)
+            + weighted(stats.life_score, self.life_weight)
+            + weighted(other, self.other_weight);
+
+        match self.decay_half_life {
+            Some(half_life) if half_life > 0 => {
+                let idle = (now_seconds - stats.last_active_at as i64).max(0);
+                let halvings = idle / half_life;
+                if halvings >= 64 {
+                    return 0;
+                }
+                // Halve once per full half-life, then interpolate linearly within the current one
+                let halved = score >> halvings;
+                let partial = (idle % half_life) as u64;
+                halved - (u128::from(halved) * u128::from(partial) / (2 * half_life as u128)) as u64
+            }
+            _ => score,
+        }
+    }
+
+    /// Name of the highest tier reached by a score.
+    pub fn tier_for(&self, score: u64) -> Option<String> {
+        self.tiers
+            .iter()
+            .filter(|tier| score >= tier.min_score)
+            .max_by_key(|tier| tier.min_score)
+            .map(|tier| tier.name.clone())
+    }
+}
+
+/// Daily XP ceiling for one category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "CategoryXpCapInput")]
+pub struct CategoryXpCap {
+    /// Category the cap applies to
+    pub category: QuestCategory,
+    /// Maximum XP a player can earn in this category per UTC day
+    pub max_daily_xp: u64,
+}
+
+/// Anti-abuse limits applied to game actions and quest claims in a season.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "ActionLimitsInput")]
+pub struct ActionLimits {
+    /// Daily XP ceilings per category
+    pub daily_caps: Vec<CategoryXpCap>,
+    /// Minimum seconds between two XP-earning actions of a player
+    pub min_action_interval: Option<i64>,
+    /// Maximum points a game may report in a single action
+    pub max_points_per_action: Option<u64>,
+}
+
+impl ActionLimits {
+    /// Daily XP ceiling for a category, if any.
+    pub fn daily_cap(&self, category: QuestCategory) -> Option<u64> {
+        self.daily_caps
+            .iter()
+            .find(|cap| cap.category == category)
+            .map(|cap| cap.max_daily_xp)
+    }
+
+    /// Whether `points` more XP in a category keep today's total within its cap.
+    pub fn within_daily_cap(&self, activity: &PlayerActivity, category: QuestCategory, points: u64) -> bool {
+        self.daily_cap(category)
+            .is_none_or(|cap| activity.xp_today(category) + points <= cap)
+    }
+}
+
+/// Why an action was rejected by the anti-abuse limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum LimitViolation {
+    /// Sooner than the minimum action interval
+    TooFrequent,
+    /// More points than allowed in one action
+    TooManyPoints,
+    /// Over the daily XP ceiling of the category
+    DailyCapReached,
+}
+
+/// XP earned in one category.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, SimpleObject)]
+pub struct CategoryXp {
+    /// Category
+    pub category: QuestCategory,
+    /// XP earned
+    pub xp: u64,
+}
+
+/// A player's XP-earning activity for the current day of a season.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct PlayerActivity {
+    /// Player's account owner
+    pub owner: String,
+    /// Season ID
+    pub season_id: u64,
+    /// UTC day (seconds since epoch / 86400) the counters refer to
+    pub day: u64,
+    /// XP earned today per category
+    pub daily_xp: Vec<CategoryXp>,
+    /// Time of the last accepted action in seconds since epoch
+    pub last_action_at: Option<i64>,
+}
+
+impl PlayerActivity {
+    /// Reset the daily counters if `day` is a new UTC day.
+    pub fn start_day(&mut self, day: u64) {
+        if self.day != day {
+            self.day = day;
+            self.daily_xp.clear();
+        }
+    }
+
+    /// XP earned today in a category.
+    pub fn xp_today(&self, category: QuestCategory) -> u64 {
+        self.daily_xp
+            .iter()
+            .find(|entry| entry.category == category)
+            .map_or(0, |entry| entry.xp)
+    }
+
+    /// Add XP earned today in a category.
+    pub fn add_xp_today(&mut self, category: QuestCategory, points: u64) {
+        match self.daily_xp.iter_mut().find(|entry| entry.category == category) {
+            Some(entry) => entry.xp += points,
+            None => self.daily_xp.push(CategoryXp { category, xp: points }),
+        }
+    }
+}
+
+/// Rejected actions of a player in a season, for spotting bots.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct ModerationRecord {
+    /// Player's account owner
+    pub owner: String,
+    /// Season ID
+    pub season_id: u64,
+    /// Actions rejected for being too frequent
+    pub too_frequent: u32,
+    /// Actions rejected for reporting too many points
+    pub too_many_points: u32,
+    /// Actions rejected by a daily XP cap
+    pub daily_cap_reached: u32,
+    /// Time of the last rejection in seconds since epoch
+    pub last_rejected_at: i64,
+}
+
+impl ModerationRecord {
+    /// Count a rejection.
+    pub fn record(&mut self, violation: LimitViolation, now_seconds: i64) {
+        match violation {
+            LimitViolation::TooFrequent => self.too_frequent += 1,
+            LimitViolation::TooManyPoints => self.too_many_points += 1,
+            LimitViolation::DailyCapReached => self.daily_cap_reached += 1,
+        }
+        self.last_rejected_at = now_seconds;
+    }
+
+    /// Total number of rejected actions.
+    pub fn total(&self) -> u32 {
+        self.too_frequent + self.too_many_points + self.daily_cap_reached
+    }
+}
+
 /// A season for XP tracking and leaderboards.
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct Season {
@@ -65,12 +522,130 @@ pub struct Season {
     pub active: bool,
     /// Optional theme (e.g., "Meme Madness", "Speed Typing")
     pub theme: Option<String>,
+    /// Seconds after `end_time` during which quests may still be claimed
+    pub grace_period: i64,
+    /// Finalization timestamp in seconds since epoch (None while open)
+    pub finalized_at: Option<u64>,
+    /// Whether the season has been archived
+    pub archived: bool,
+    /// Template for the season created automatically when this one is finalized
+    pub rollover: Option<SeasonTemplate>,
+    /// Reward brackets, checked in order
+    pub reward_tiers: Vec<RewardTier>,
+    /// Unclaimed native tokens held by the application for this season
+    pub prize_pool: Amount,
+    /// Number of ranked players when the season was finalized
+    pub ranked_players: u32,
+    /// Share of each tier's amount paid per claim, in millionths, lowered at
+    /// finalization when the prize pool cannot cover every ranked player
+    pub payout_ppm: u32,
+    /// Number of players whose final rank has been snapshotted so far
+    pub ranks_snapshotted: u32,
+    /// Leaderboard cursor of the last player whose rank was snapshotted
+    pub snapshot_cursor: Option<String>,
+    /// Season of the same theme finalized before this one, for career streaks
+    pub previous_season: Option<u64>,
+    /// Arcade Skill Index formula for this season
+    pub skill_config: SkillIndexConfig,
+    /// Anti-abuse limits for this season
+    pub limits: ActionLimits,
+}
+
+impl Season {
+    /// Whether the season is finalized but some final ranks are still to be
+    /// snapshotted by further `FinalizeSeason` calls.
+    pub fn snapshot_pending(&self) -> bool {
+        self.finalized_at.is_some() && self.ranks_snapshotted < self.ranked_players
+    }
+
+    /// Derive the lifecycle phase at the given time.
+    pub fn phase(&self, now_seconds: i64) -> SeasonPhase {
+        if self.archived {
+            SeasonPhase::Archived
+        } else if !self.active || now_seconds > self.end_time + self.grace_period {
+            SeasonPhase::Closed
+        } else if now_seconds < self.start_time {
+            SeasonPhase::Scheduled
+        } else if now_seconds <= self.end_time {
+            SeasonPhase::Live
+        } else {
+            SeasonPhase::Grace
+        }
+    }
+
+    /// Reward owed for a final rank, if any tier covers it, scaled by the
+    /// season's payout share.
+    pub fn reward_for_rank(&self, rank: u32) -> Option<Amount> {
+        self.reward_tiers
+            .iter()
+            .find(|tier| tier.covers(rank, self.ranked_players))
+            .map(|tier| scale_amount(tier.amount, self.payout_ppm))
+    }
+
+    /// Lower the payout share so the prize pool covers every rank out of the
+    /// final `ranked_players`. More players may have joined percentile tiers
+    /// since `SetRewardTiers` checked the pool, so this runs at finalization.
+    pub fn scale_payouts_to_prize_pool(&mut self) {
+        let owed = self.total_payout(self.ranked_players).to_attos();
+        let pool = self.prize_pool.to_attos();
+        self.payout_ppm = if owed <= pool {
+            FULL_PAYOUT_PPM
+        } else {
+            // Rounded down, so the scaled payouts never exceed the pool
+            (pool.saturating_mul(u128::from(FULL_PAYOUT_PPM)) / owed) as u32
+        };
+    }
+
+    /// Total paid out if every covered rank out of `ranked_players` claims.
+    /// Each rank is paid by the first tier covering it, so a tier only pays
+    /// for the ranks past those of the tiers before it.
+    pub fn total_payout(&self, ranked_players: u32) -> Amount {
+        let mut paid_up_to = 0;
+        let mut total = Amount::ZERO;
+        for tier in &self.reward_tiers {
+            let last_rank = tier.last_rank(ranked_players);
+            if last_rank > paid_up_to {
+                total = total.saturating_add(tier.amount.saturating_mul(u128::from(last_rank - paid_up_to)));
+                paid_up_to = last_rank;
+            }
+        }
+        total
+    }
+
+    /// Whether the prize pool covers the tiers for `ranked_players`, counting
+    /// at least enough players to fill every rank-bounded tier.
+    pub fn prize_pool_covers_tiers(&self, ranked_players: u32) -> bool {
+        let filled = self
+            .reward_tiers
+            .iter()
+            .filter_map(|tier| tier.max_rank)
+            .fold(ranked_players, u32::max);
+        self.total_payout(filled) <= self.prize_pool
+    }
+
+    /// Whether this season's window overlaps the given one.
+    pub fn overlaps(&self, start_time: i64, end_time: i64) -> bool {
+        self.start_time < end_time && start_time < self.end_time
+    }
+}
+
+/// A game application allowed to report XP to Arcade Nexus.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RegisteredGame {
+    /// Application ID of the game contract
+    pub application_id: ApplicationId,
+    /// Display name (e.g., "Typing Arena")
+    pub name: String,
+    /// Category credited for XP reported by this game
+    pub category: QuestCategory,
+    /// Registration timestamp in seconds since epoch
+    pub registered_at: u64,
 }
 
 /// Player statistics for a specific season.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
 pub struct PlayerSeasonStats {
-    /// Player's chain ID
+    /// Player's account owner
     pub owner: String,
     /// Season ID
     pub season_id: u64,
@@ -86,8 +661,263 @@ pub struct PlayerSeasonStats {
     pub life_score: u64,
     /// Number of quests completed
     pub completed_quests: u32,
+    /// Prediction rounds won (attested by Prediction Pulse)
+    pub prediction_wins: u64,
+    /// Meme Battle matches voted in (attested by Meme Battle)
+    pub meme_battle_votes: u64,
+    /// Typing challenges completed (attested by Typing Arena)
+    pub typing_challenges: u64,
+    /// Highest generation reached (attested by Game of Life)
+    pub best_life_generation: u64,
     /// Rank snapshot when season closes (optional)
     pub rank_snapshot: Option<u32>,
+    /// Time of the last XP gain in seconds since epoch
+    pub last_active_at: u64,
+}
+
+impl PlayerSeasonStats {
+    /// Add XP to the total and to the score of the given category.
+    pub fn add_xp(&mut self, category: QuestCategory, points: u64) {
+        self.total_xp += points;
+        match category {
+            QuestCategory::Prediction => self.prediction_score += points,
+            QuestCategory::Meme => self.meme_score += points,
+            QuestCategory::Typing => self.typing_score += points,
+            QuestCategory::Life => self.life_score += points,
+            QuestCategory::Mixed | QuestCategory::Other => {
+                // For mixed/other, only the total is increased
+            }
+        }
+    }
+
+    /// Apply an attested game event to the event counters.
+    pub fn apply_event(&mut self, kind: GameEventKind, value: u64) {
+        match kind {
+            GameEventKind::PredictionWin => self.prediction_wins += value,
+            GameEventKind::MemeBattleVote => self.meme_battle_votes += value,
+            GameEventKind::TypingChallengeCompleted => self.typing_challenges += value,
+            GameEventKind::LifeGenerationReached => {
+                self.best_life_generation = self.best_life_generation.max(value)
+            }
+        }
+    }
+
+    /// Current value of a requirement metric.
+    pub fn metric_value(&self, metric: RequirementMetric) -> u64 {
+        match metric {
+            RequirementMetric::TotalXp => self.total_xp,
+            RequirementMetric::PredictionScore => self.prediction_score,
+            RequirementMetric::MemeScore => self.meme_score,
+            RequirementMetric::TypingScore => self.typing_score,
+            RequirementMetric::LifeScore => self.life_score,
+            RequirementMetric::PredictionWins => self.prediction_wins,
+            RequirementMetric::MemeBattleVotes => self.meme_battle_votes,
+            RequirementMetric::TypingChallenges => self.typing_challenges,
+            RequirementMetric::LifeGeneration => self.best_life_generation,
+        }
+    }
+}
+
+/// A player's stats together with their position on a season leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RankedPlayer {
+    /// 1-based rank in the season
+    pub rank: u32,
+    /// The player's season stats
+    pub stats: PlayerSeasonStats,
+}
+
+/// One page of a season leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct LeaderboardPage {
+    /// Players on this page, best first
+    pub entries: Vec<PlayerSeasonStats>,
+    /// Cursor to pass as `after` for the next page (None on the last page)
+    pub next_cursor: Option<String>,
+}
+
+/// A badge awarded automatically when a season is finalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum AchievementKind {
+    /// Finished a season in first place
+    SeasonChampion,
+    /// Finished a season in the top 10
+    TopTen,
+    /// Finished in the top 10 of three consecutive finalized seasons
+    TopTenThreeInARow,
+    /// Played five seasons
+    Veteran,
+    /// Completed 50 quests across all seasons
+    QuestMaster,
+    /// Earned 100,000 lifetime XP
+    XpLegend,
+}
+
+/// An achievement held by a player.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Achievement {
+    /// Which achievement
+    pub kind: AchievementKind,
+    /// Season whose finalization awarded it
+    pub season_id: u64,
+    /// Award timestamp in seconds since epoch
+    pub awarded_at: u64,
+}
+
+/// A player's final placement in one season.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SeasonPlacement {
+    /// Season ID
+    pub season_id: u64,
+    /// Final rank
+    pub rank: u32,
+    /// XP earned in the season
+    pub total_xp: u64,
+}
+
+/// A run of consecutive top 10 finishes in the seasons of one theme.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct TopTenStreak {
+    /// Season theme (None for seasons without a theme)
+    pub theme: Option<String>,
+    /// Consecutive finalized seasons of the theme finished in the top 10
+    pub streak: u32,
+    /// Last season of the theme finished in the top 10
+    pub last_season: u64,
+}
+
+/// Lifetime record of a player, accumulated from every finalized season.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct CareerProfile {
+    /// Player's account owner
+    pub owner: String,
+    /// Lifetime XP
+    pub total_xp: u64,
+    /// Lifetime XP from Prediction Pulse
+    pub prediction_score: u64,
+    /// Lifetime XP from Meme Auction
+    pub meme_score: u64,
+    /// Lifetime XP from Typing Arena
+    pub typing_score: u64,
+    /// Lifetime XP from Game of Life
+    pub life_score: u64,
+    /// Number of finalized seasons played
+    pub seasons_played: u32,
+    /// Quests completed across all seasons
+    pub quests_completed: u32,
+    /// Best final rank ever reached
+    pub best_rank: Option<u32>,
+    /// Final placement in each season played
+    pub placements: Vec<SeasonPlacement>,
+    /// Current top 10 streak in each theme played
+    pub top_ten_streaks: Vec<TopTenStreak>,
+    /// Achievements earned, oldest first
+    pub achievements: Vec<Achievement>,
+}
+
+impl CareerProfile {
+    /// Fold a finalized season into the career and award any achievements
+    /// newly earned. `previous_season` is the season of the same theme
+    /// finalized before this one.
+    pub fn record_season(
+        &mut self,
+        stats: &PlayerSeasonStats,
+        rank: u32,
+        theme: &Option<String>,
+        previous_season: Option<u64>,
+        now_seconds: u64,
+    ) {
+        self.total_xp += stats.total_xp;
+        self.prediction_score += stats.prediction_score;
+        self.meme_score += stats.meme_score;
+        self.typing_score += stats.typing_score;
+        self.life_score += stats.life_score;
+        self.seasons_played += 1;
+        self.quests_completed += stats.completed_quests;
+        self.best_rank = Some(self.best_rank.map_or(rank, |best| best.min(rank)));
+        self.placements.push(SeasonPlacement {
+            season_id: stats.season_id,
+            rank,
+            total_xp: stats.total_xp,
+        });
+
+        // Streaks only continue through seasons of the same theme
+        let current = self
+            .top_ten_streaks
+            .iter()
+            .position(|s| &s.theme == theme)
+            .map(|i| self.top_ten_streaks.remove(i));
+        let streak = if rank <= 10 {
+            let streak = match current {
+                Some(s) if previous_season == Some(s.last_season) => s.streak + 1,
+                _ => 1,
+            };
+            self.top_ten_streaks.push(TopTenStreak {
+                theme: theme.clone(),
+                streak,
+                last_season: stats.season_id,
+            });
+            streak
+        } else {
+            0
+        };
+
+        let earned = [
+            (AchievementKind::SeasonChampion, rank == 1),
+            (AchievementKind::TopTen, rank <= 10),
+            (AchievementKind::TopTenThreeInARow, streak >= 3),
+            (AchievementKind::Veteran, self.seasons_played >= 5),
+            (AchievementKind::QuestMaster, self.quests_completed >= 50),
+            (AchievementKind::XpLegend, self.total_xp >= 100_000),
+        ];
+        for (kind, condition) in earned {
+            if condition && !self.has_achievement(kind) {
+                self.achievements.push(Achievement {
+                    kind,
+                    season_id: stats.season_id,
+                    awarded_at: now_seconds,
+                });
+            }
+        }
+    }
+
+    /// Whether the player holds an achievement.
+    pub fn has_achievement(&self, kind: AchievementKind) -> bool {
+        self.achievements.iter().any(|a| a.kind == kind)
+    }
+}
+
+/// Hard cap on the size of any guild.
+pub const MAX_GUILD_MEMBERS: u32 = 100;
+
+/// A team of players whose season XP is pooled.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Guild {
+    /// Unique guild ID
+    pub id: u64,
+    /// Guild name
+    pub name: String,
+    /// Account owner of the guild leader
+    pub leader: String,
+    /// Member account owners, including the leader
+    pub members: Vec<String>,
+    /// Maximum number of members
+    pub max_members: u32,
+    /// Whether joining requires an invite from the leader
+    pub invite_only: bool,
+    /// Creation timestamp in seconds since epoch
+    pub created_at: u64,
+}
+
+/// A guild's pooled XP for one season.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct GuildSeasonStats {
+    /// Guild ID
+    pub guild_id: u64,
+    /// Season ID
+    pub season_id: u64,
+    /// Season XP of the current members
+    pub total_xp: u64,
 }
 
 /// A quest that players can complete for XP.
@@ -107,14 +937,64 @@ pub struct Quest {
     pub reward_xp: u64,
     /// Human-readable requirements
     pub requirements_text: String,
+    /// Machine-checkable requirement validated on completion (None = self-claimed)
+    pub requirement: Option<QuestRequirement>,
     /// Who created this quest
     pub created_by: String,
-    /// Whether quest is active
+    /// Whether quest is active (false while paused or once retired)
     pub active: bool,
+    /// Whether quest has been permanently retired
+    pub retired: bool,
     /// Whether this was suggested by AI
     pub ai_suggested: bool,
     /// Creation timestamp
     pub created_at: u64,
+    /// Optional opening time in seconds since epoch
+    pub starts_at: Option<i64>,
+    /// Optional closing time in seconds since epoch
+    pub ends_at: Option<i64>,
+    /// Maximum number of completions across all players (first-N quests)
+    pub max_completions: Option<u32>,
+    /// Number of completions so far
+    pub completions: u32,
+    /// Seconds a player must wait before repeating (None = not repeatable)
+    pub cooldown: Option<i64>,
+    /// Quest a player must complete before this one unlocks
+    pub prerequisite: Option<u64>,
+    /// Partial-XP milestones on the way to the requirement, in ascending order
+    pub milestones: Vec<QuestMilestone>,
+    /// Off-chain generator that proposed this quest
+    pub generator_id: Option<String>,
+    /// Template this quest was filled from
+    pub template_id: Option<u64>,
+    /// Hash of the generator prompt that produced this quest
+    pub prompt_hash: Option<String>,
+}
+
+impl Quest {
+    /// Whether the quest's own time window is open.
+    pub fn is_open(&self, now_seconds: i64) -> bool {
+        self.starts_at.is_none_or(|start| now_seconds >= start)
+            && self.ends_at.is_none_or(|end| now_seconds <= end)
+    }
+
+    /// Check that milestones are strictly ascending and within the requirement.
+    pub fn milestones_valid(requirement: Option<&QuestRequirement>, milestones: &[QuestMilestone]) -> bool {
+        if milestones.is_empty() {
+            return true;
+        }
+        let requirement = match requirement {
+            Some(r) => r,
+            None => return false, // Milestones need a metric to track
+        };
+        milestones.windows(2).all(|pair| pair[0].target < pair[1].target)
+            && milestones.iter().all(|m| m.target <= requirement.target)
+    }
+
+    /// Whether the completion cap has been reached.
+    pub fn is_full(&self) -> bool {
+        self.max_completions.is_some_and(|max| self.completions >= max)
+    }
 }
 
 /// Player's progress on a quest.
@@ -122,33 +1002,259 @@ pub struct Quest {
 pub struct QuestProgress {
     /// Quest ID
     pub quest_id: u64,
-    /// Player's chain ID
+    /// Player's account owner
     pub owner: String,
     /// Whether quest is completed
     pub completed: bool,
-    /// Completion timestamp (if completed)
+    /// Latest completion timestamp (if completed)
     pub completed_at: Option<i64>,
+    /// Number of times completed (repeatable quests)
+    pub times_completed: u32,
+    /// Last recorded value of the quest requirement's metric
+    pub progress: u64,
+    /// Value of the metric at the latest completion; a repeat must gain the
+    /// requirement's target on top of it
+    pub baseline: u64,
+    /// Number of milestones reached (and paid)
+    pub milestones_reached: u32,
+}
+
+/// A player's progress on a quest with its completion percentage.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct QuestProgressReport {
+    /// Stored progress record
+    pub progress: QuestProgress,
+    /// Current value of the quest requirement's metric
+    pub current: u64,
+    /// Value required to complete the quest (None for self-claimed quests)
+    pub target: Option<u64>,
+    /// Completion percentage, 0-100
+    pub percent: u32,
+}
+
+/// Maximum number of quests created by one `CreateQuestsFromTemplates` operation.
+pub const MAX_QUEST_BATCH: usize = 50;
+
+/// Maximum number of final ranks snapshotted by one finalization operation.
+pub const MAX_FINALIZE_BATCH: usize = 100;
+
+/// Maximum number of skill indexes recomputed by one `RefreshSkillIndexes` operation.
+pub const MAX_SKILL_REFRESH_BATCH: usize = 100;
+
+/// Maximum number of days returned by one XP timeline query.
+pub const MAX_TIMELINE_DAYS: usize = 366;
+
+/// Maximum number of players returned by one leaderboard query.
+pub const MAX_LEADERBOARD_PAGE: usize = 200;
+
+/// Payout share, in millionths, at which reward tiers are paid in full.
+pub const FULL_PAYOUT_PPM: u32 = 1_000_000;
+
+/// Scale an amount by a share given in millionths, rounding down.
+fn scale_amount(amount: Amount, ppm: u32) -> Amount {
+    if ppm >= FULL_PAYOUT_PPM {
+        return amount;
+    }
+    let attos = amount.to_attos();
+    let ppm = u128::from(ppm);
+    let full = u128::from(FULL_PAYOUT_PPM);
+    // Split the division so large amounts do not overflow
+    Amount::from_attos(attos / full * ppm + attos % full * ppm / full)
+}
+
+/// A reusable quest shape with parameter slots, filled in by off-chain generators.
+///
+/// Texts may contain `{target}` and `{reward}` slots, plus any other `{name}`
+/// slot filled from the instance's parameters.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct QuestTemplate {
+    /// Unique template ID
+    pub id: u64,
+    /// Template name (e.g., "Typing streak")
+    pub name: String,
+    /// Quest title with slots
+    pub title: String,
+    /// Quest description with slots
+    pub description: String,
+    /// Human-readable requirements with slots
+    pub requirements_text: String,
+    /// Category of generated quests
+    pub category: QuestCategory,
+    /// Statistic checked by generated quests
+    pub metric: RequirementMetric,
+    /// Smallest allowed requirement target
+    pub min_target: u64,
+    /// Largest allowed requirement target
+    pub max_target: u64,
+    /// Smallest allowed XP reward
+    pub min_reward_xp: u64,
+    /// Largest allowed XP reward
+    pub max_reward_xp: u64,
+    /// Who created this template
+    pub created_by: String,
+    /// Creation timestamp
+    pub created_at: u64,
+    /// Whether new quests can still be filled from this template
+    pub active: bool,
+}
+
+impl QuestTemplate {
+    /// Whether the target and reward bounds are consistent.
+    pub fn bounds_valid(&self) -> bool {
+        self.min_target > 0 && self.min_target <= self.max_target && self.min_reward_xp <= self.max_reward_xp
+    }
+
+    /// Fill the slots of a template text. Returns None if a slot is left unfilled.
+    pub fn fill(&self, text: &str, instance: &QuestInstance) -> Option<String> {
+        let mut filled = text
+            .replace("{target}", &instance.target.to_string())
+            .replace("{reward}", &instance.reward_xp.to_string());
+        for param in &instance.params {
+            filled = filled.replace(&format!("{{{}}}", param.name), &param.value);
+        }
+        if filled.contains('{') {
+            return None;
+        }
+        Some(filled)
+    }
+}
+
+/// A value for a named template slot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "TemplateParamInput")]
+pub struct TemplateParam {
+    /// Slot name, without braces
+    pub name: String,
+    /// Text substituted for the slot
+    pub value: String,
+}
+
+/// One quest to fill from a template in a batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "QuestInstanceInput")]
+pub struct QuestInstance {
+    /// Template to fill
+    pub template_id: u64,
+    /// Requirement target, within the template bounds
+    pub target: u64,
+    /// XP reward, within the template bounds
+    pub reward_xp: u64,
+    /// Values for the template's named slots
+    pub params: Vec<TemplateParam>,
+    /// Optional opening time in seconds since epoch
+    pub starts_at: Option<i64>,
+    /// Optional closing time in seconds since epoch
+    pub ends_at: Option<i64>,
+    /// Cap on completions across all players
+    pub max_completions: Option<u32>,
+    /// Hash of the generator prompt that produced this quest
+    pub prompt_hash: Option<String>,
+}
+
+/// A player's season stats as one row of metric values.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct PlayerExportRow {
+    /// Player's account owner
+    pub owner: String,
+    /// Metric values, in the order of `SeasonExport::columns`
+    pub values: Vec<u64>,
+    /// Quests completed this season
+    pub completed_quests: u32,
+}
+
+/// Compact per-season aggregate stats for off-chain quest generators.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SeasonExport {
+    /// Season ID
+    pub season_id: u64,
+    /// Season theme, if any
+    pub theme: Option<String>,
+    /// Number of players with stats
+    pub players: u32,
+    /// Metric names of the `totals` and row values
+    pub columns: Vec<RequirementMetric>,
+    /// Sum of each metric over all players
+    pub totals: Vec<u64>,
+    /// Number of quests in the season
+    pub quests: u32,
+    /// Quest completions across all players
+    pub quest_completions: u32,
+    /// Top players by XP, one row each
+    pub rows: Vec<PlayerExportRow>,
 }
 
 /// Arcade Skill Index - aggregated player score.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
 pub struct ArcadeSkillIndex {
-    /// Player's chain ID
+    /// Player's account owner
     pub owner: String,
     /// Season ID
     pub season_id: u64,
     /// Total XP
     pub total_xp: u64,
-    /// Overall score (weighted sum of all scores)
+    /// Overall score (weighted sum of category scores, after decay)
     pub overall_score: u64,
-    /// Rank hint (Bronze, Silver, Gold, Legendary)
+    /// Name of the season's skill tier reached by the overall score
     pub rank_hint: Option<String>,
+    /// When the index was last computed, in seconds since epoch
+    pub computed_at: u64,
+}
+
+/// What an activity log entry records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
+pub enum ActivityKind {
+    /// XP reported by a game
+    XpGranted,
+    /// Quest completed and its reward paid
+    QuestCompleted,
+    /// Quest milestone reached and its reward paid
+    MilestoneReached,
+    /// Season created
+    SeasonCreated,
+    /// Season finalized
+    SeasonFinalized,
+    /// Season archived
+    SeasonArchived,
+}
+
+/// An entry in the append-only activity log.
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct ActivityEntry {
+    /// Position in the log
+    pub id: u64,
+    /// What happened
+    pub kind: ActivityKind,
+    /// Season concerned
+    pub season_id: u64,
+    /// Player concerned (None for season transitions)
+    pub player: Option<String>,
+    /// XP granted (0 for season transitions)
+    pub xp: u64,
+    /// Quest concerned, if any
+    pub quest_id: Option<u64>,
+    /// Game application that reported the XP, if any
+    pub game: Option<ApplicationId>,
+    /// Timestamp in seconds since epoch
+    pub timestamp: i64,
+}
+
+/// XP granted in a season during one UTC day.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, SimpleObject)]
+pub struct XpBucket {
+    /// Season ID
+    pub season_id: u64,
+    /// Days since epoch
+    pub day: u64,
+    /// XP granted that day
+    pub xp: u64,
+    /// Number of XP grants that day
+    pub grants: u32,
 }
 
 /// Operations that can be performed on the ArcadeNexus contract.
 #[derive(Debug, Clone, Serialize, Deserialize, GraphQLMutationRoot)]
 pub enum Operation {
-    /// Create a new season (admin only).
+    /// Create a new season (season managers only).
     CreateSeason {
         title: String,
         description: String,
@@ -158,21 +1264,116 @@ pub enum Operation {
         end_time: i64,
         /// Optional theme
         theme: Option<String>,
+        /// Seconds after the end during which quests may still be claimed
+        grace_period: Option<i64>,
+        /// Template for automatically creating the next season
+        rollover: Option<SeasonTemplate>,
+        /// Reward brackets paid out from the prize pool. The pool is empty
+        /// at creation, so tiers are checked against it by `SetRewardTiers`.
+        reward_tiers: Option<Vec<RewardTier>>,
+        /// Arcade Skill Index formula (defaults to equal weights, no decay)
+        skill_config: Option<SkillIndexConfig>,
+        /// Anti-abuse limits (defaults to none)
+        limits: Option<ActionLimits>,
     },
     
-    /// Close a season (admin only).
+    /// Close a season early (season managers only).
     CloseSeason {
         season_id: u64,
     },
+
+    /// Finalize a season whose grace period is over (anyone).
+    ///
+    /// Snapshots ranks and creates the next season from its rollover template.
+    /// Ranks are snapshotted `MAX_FINALIZE_BATCH` players at a time; call again
+    /// on a finalized season until `ranks_pending` is zero.
+    FinalizeSeason {
+        season_id: u64,
+    },
+
+    /// Archive a closed season (season managers only).
+    ArchiveSeason {
+        season_id: u64,
+    },
+
+    /// Replace the reward tiers of an open season (season managers only).
+    ///
+    /// The prize pool must cover the tiers for the players ranked so far,
+    /// and for at least as many players as the largest `max_rank`.
+    SetRewardTiers {
+        season_id: u64,
+        tiers: Vec<RewardTier>,
+    },
+
+    /// Replace the anti-abuse limits of an open season (season managers only).
+    SetActionLimits {
+        season_id: u64,
+        limits: ActionLimits,
+    },
+
+    /// Move native tokens from the signer's account into a season's prize pool.
+    ///
+    /// Deposits for unknown or archived seasons are refunded.
+    FundPrizePool {
+        season_id: u64,
+        amount: Amount,
+    },
+
+    /// Recompute a player's persisted skill index, applying decay (anyone).
+    RefreshSkillIndex {
+        season_id: u64,
+        owner: String,
+    },
+
+    /// Recompute the persisted skill indexes of up to `MAX_SKILL_REFRESH_BATCH`
+    /// players of a season, in leaderboard order after the cursor `after`
+    /// (anyone). Keeps decay reflected in `skillLeaderboard` between the
+    /// players' own actions.
+    RefreshSkillIndexes {
+        season_id: u64,
+        after: Option<String>,
+    },
+
+    /// Claim the signer's reward for a finalized season, paid to their
+    /// account on the chain the claim was submitted from.
+    ClaimSeasonReward {
+        season_id: u64,
+    },
     
-    /// Record XP from a game action.
+    /// Record XP earned by the signer in a game action.
+    ///
+    /// Only accepted as an authenticated cross-application call from a
+    /// registered game; the category is taken from the game's registration.
     RecordGameAction {
-        season_id: u64,
-        category: QuestCategory,
+        /// Season to credit (None = every live season)
+        season_id: Option<u64>,
         points: u64,
     },
+
+    /// Record a game event of the signer used to verify quest requirements.
+    ///
+    /// Only accepted as an authenticated cross-application call from a
+    /// registered game whose category matches the event.
+    RecordGameEvent {
+        /// Season to credit (None = every live season)
+        season_id: Option<u64>,
+        kind: GameEventKind,
+        value: u64,
+    },
+
+    /// Register a game application allowed to report XP (admin only).
+    RegisterGame {
+        application_id: ApplicationId,
+        name: String,
+        category: QuestCategory,
+    },
+
+    /// Remove a game application from the registry (admin only).
+    UnregisterGame {
+        application_id: ApplicationId,
+    },
     
-    /// Create a new quest.
+    /// Create a new quest (quest authors only).
     CreateQuest {
         season_id: u64,
         title: String,
@@ -180,11 +1381,599 @@ pub enum Operation {
         category: QuestCategory,
         reward_xp: u64,
         requirements_text: String,
+        /// Required for AI-suggested quests
+        requirement: Option<QuestRequirement>,
         ai_suggested: bool,
+        /// Optional opening time in seconds since epoch
+        starts_at: Option<i64>,
+        /// Optional closing time in seconds since epoch
+        ends_at: Option<i64>,
+        /// Cap on completions across all players
+        max_completions: Option<u32>,
+        /// Makes the quest repeatable after this many seconds
+        cooldown: Option<i64>,
+        /// Quest that must be completed first
+        prerequisite: Option<u64>,
+        /// Partial-XP milestones (requires a requirement)
+        milestones: Option<Vec<QuestMilestone>>,
+        /// Off-chain generator that proposed the quest
+        generator_id: Option<String>,
+        /// Hash of the generator prompt that produced the quest
+        prompt_hash: Option<String>,
+    },
+
+    /// Create a quest template (quest authors only).
+    CreateQuestTemplate {
+        name: String,
+        title: String,
+        description: String,
+        requirements_text: String,
+        category: QuestCategory,
+        metric: RequirementMetric,
+        min_target: u64,
+        max_target: u64,
+        min_reward_xp: u64,
+        max_reward_xp: u64,
+    },
+
+    /// Stop filling new quests from a template (quest authors only).
+    RetireQuestTemplate {
+        template_id: u64,
+    },
+
+    /// Create a batch of AI-suggested quests from templates (quest authors only).
+    /// The batch is rejected as a whole if any quest is invalid.
+    CreateQuestsFromTemplates {
+        season_id: u64,
+        generator_id: String,
+        quests: Vec<QuestInstance>,
+    },
+
+    /// Edit a quest; omitted fields are left unchanged (quest authors only).
+    /// Optional quest fields are cleared by passing a `Clearable` without value.
+    UpdateQuest {
+        quest_id: u64,
+        title: Option<String>,
+        description: Option<String>,
+        reward_xp: Option<u64>,
+        requirements_text: Option<String>,
+        requirement: Option<Clearable<QuestRequirement>>,
+        starts_at: Option<Clearable<i64>>,
+        ends_at: Option<Clearable<i64>>,
+        max_completions: Option<Clearable<u32>>,
+        cooldown: Option<Clearable<i64>>,
+        milestones: Option<Vec<QuestMilestone>>,
+    },
+
+    /// Temporarily stop completions of a quest (quest authors only).
+    PauseQuest {
+        quest_id: u64,
+    },
+
+    /// Resume a paused quest (quest authors only).
+    ResumeQuest {
+        quest_id: u64,
+    },
+
+    /// Permanently retire a quest (quest authors only).
+    RetireQuest {
+        quest_id: u64,
     },
     
-    /// Mark a quest as completed (claim XP).
+    /// Mark a quest as completed (claim XP) once its requirement is met.
     CompleteQuest {
         quest_id: u64,
     },
+
+    /// Create a guild led by the caller.
+    CreateGuild {
+        name: String,
+        /// Defaults to and is capped at MAX_GUILD_MEMBERS
+        max_members: Option<u32>,
+        invite_only: bool,
+    },
+
+    /// Invite a player to the caller's guild (guild leader only).
+    InviteToGuild {
+        player: String,
+    },
+
+    /// Join a guild; invite-only guilds require a pending invite.
+    JoinGuild {
+        guild_id: u64,
+    },
+
+    /// Leave the caller's guild. A leaving leader hands over to the next member.
+    LeaveGuild,
+
+    /// Remove a member from the caller's guild (guild leader only).
+    KickFromGuild {
+        player: String,
+    },
+
+    /// Grant a role to an account (admin only).
+    GrantRole {
+        member: String,
+        role: Role,
+    },
+
+    /// Revoke a role from an account (admin only).
+    ///
+    /// The last remaining admin cannot be revoked.
+    RevokeRole {
+        member: String,
+        role: Role,
+    },
+
+    /// Hand the caller's admin role over to another account (admin only).
+    TransferAdmin {
+        new_admin: String,
+    },
+}
+
+/// Cross-chain messages between ArcadeNexus instances.
+///
+/// All messages are sent with authentication; the player is the signer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    /// An operation submitted on another chain, applied on the creator chain.
+    Operation(Box<Operation>),
+
+    /// XP reported by a game on another chain, forwarded to the creator chain.
+    RecordGameAction {
+        /// The game application that reported the action
+        game: ApplicationId,
+        season_id: Option<u64>,
+        points: u64,
+    },
+
+    /// Deposit already transferred to the creator chain, to be added to a prize pool.
+    FundPrizePool {
+        season_id: u64,
+        amount: Amount,
+    },
+
+    /// Game event reported on another chain, forwarded to the creator chain.
+    RecordGameEvent {
+        /// The game application that attested the event
+        game: ApplicationId,
+        season_id: Option<u64>,
+        kind: GameEventKind,
+        value: u64,
+    },
+}
+
+/// Result of an operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OperationResult {
+    /// Season was created
+    SeasonCreated { season_id: u64 },
+    /// Season settings or status were updated
+    SeasonUpdated,
+    /// Season was finalized, possibly rolling over into a new season
+    SeasonFinalized {
+        season_id: u64,
+        next_season_id: Option<u64>,
+        /// Players whose final rank is still to be snapshotted
+        ranks_pending: u32,
+    },
+    /// Prize pool was funded
+    PrizePoolFunded { prize_pool: Amount },
+    /// Season reward was paid out
+    RewardClaimed { amount: Amount },
+    /// Skill index was recomputed
+    SkillIndexRefreshed { overall_score: u64 },
+    /// A batch of skill indexes was recomputed; `next` is the cursor to
+    /// continue from, None once the whole season is done
+    SkillIndexesRefreshed { next: Option<String> },
+    /// Game report was applied on this chain
+    ActionRecorded,
+    /// Operation was forwarded to the creator chain
+    ActionForwarded,
+    /// Game registry was updated
+    RegistryUpdated,
+    /// Quest was created
+    QuestCreated { quest_id: u64 },
+    /// Batch of quests was created from templates
+    QuestsCreated { quest_ids: Vec<u64> },
+    /// Quest template was created
+    QuestTemplateCreated { template_id: u64 },
+    /// Quest template was retired
+    QuestTemplateUpdated,
+    /// Quest was edited, paused, resumed or retired
+    QuestUpdated,
+    /// Quest was completed and its XP awarded
+    QuestCompleted { reward_xp: u64 },
+    /// Guild was created
+    GuildCreated { guild_id: u64 },
+    /// Guild membership or invites were updated
+    GuildUpdated,
+    /// Role assignments were updated
+    RolesUpdated,
+    /// Operation failed
+    Error(NexusError),
+}
+
+/// Errors that can occur in the ArcadeNexus application
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+pub enum NexusError {
+    #[error("Operation must be signed by an account owner")]
+    Unauthenticated,
+
+    #[error("Unauthorized: the caller lacks the required role")]
+    Unauthorized,
+
+    #[error("Only game applications can report to Arcade Nexus")]
+    NotCalledByApplication,
+
+    #[error("Game application is not registered")]
+    UnknownGame,
+
+    #[error("Game is not allowed to attest this kind of event")]
+    WrongEventCategory,
+
+    #[error("Operation cannot be forwarded to the creator chain")]
+    NotForwardable,
+
+    #[error("Invalid time range")]
+    InvalidTimeRange,
+
+    #[error("Invalid rollover template")]
+    InvalidRolloverTemplate,
+
+    #[error("Another open season of the same theme overlaps this time range")]
+    OverlappingSeason,
+
+    #[error("Season not found")]
+    SeasonNotFound,
+
+    #[error("Season is not active")]
+    SeasonInactive,
+
+    #[error("Season grace period is not over yet")]
+    SeasonNotExpired,
+
+    #[error("Season is not finalized")]
+    SeasonNotFinalized,
+
+    #[error("Season ranks are still being snapshotted")]
+    RanksPending,
+
+    #[error("Season is archived")]
+    SeasonArchived,
+
+    #[error("Outside the season's time window")]
+    OutsideSeasonWindow,
+
+    #[error("No season is live")]
+    NoLiveSeason,
+
+    #[error("Quest not found")]
+    QuestNotFound,
+
+    #[error("Quest is not active")]
+    QuestInactive,
+
+    #[error("Quest is retired")]
+    QuestRetired,
+
+    #[error("Outside the quest's time window")]
+    OutsideQuestWindow,
+
+    #[error("Quest has no completion slots left")]
+    QuestFull,
+
+    #[error("Quest already completed")]
+    QuestAlreadyCompleted,
+
+    #[error("Quest cooldown has not elapsed")]
+    CooldownNotElapsed,
+
+    #[error("Prerequisite quest not found in this season")]
+    PrerequisiteNotFound,
+
+    #[error("Prerequisite quest not completed")]
+    PrerequisiteNotCompleted,
+
+    #[error("Quest requirement not met")]
+    RequirementNotMet,
+
+    #[error("AI-suggested quests need a machine-checkable requirement")]
+    MissingRequirement,
+
+    #[error("Invalid cooldown")]
+    InvalidCooldown,
+
+    #[error("Invalid milestones")]
+    InvalidMilestones,
+
+    #[error("Reward tiers need a max rank or a percentile between 1 and 100")]
+    InvalidRewardTiers,
+
+    #[error("Reward tiers would pay out more than the prize pool holds")]
+    RewardTiersExceedPrizePool,
+
+    #[error("Quest template not found")]
+    TemplateNotFound,
+
+    #[error("Quest template is retired")]
+    TemplateRetired,
+
+    #[error("Invalid quest template bounds")]
+    InvalidTemplateBounds,
+
+    #[error("Quest target or reward is outside the template bounds")]
+    OutsideTemplateBounds,
+
+    #[error("Template slot left unfilled")]
+    UnfilledTemplateSlot,
+
+    #[error("Quest batch is empty or too large")]
+    InvalidBatchSize,
+
+    #[error("Invalid leaderboard cursor")]
+    InvalidCursor,
+
+    #[error("Rate limited: {0:?}")]
+    RateLimited(LimitViolation),
+
+    #[error("Player has no stats in this season")]
+    NoStats,
+
+    #[error("Player is not ranked in this season")]
+    NotRanked,
+
+    #[error("No reward for this rank")]
+    NoRewardForRank,
+
+    #[error("Reward already claimed")]
+    AlreadyClaimed,
+
+    #[error("Prize pool exhausted")]
+    PrizePoolExhausted,
+
+    #[error("Invalid guild name")]
+    InvalidGuildName,
+
+    #[error("Invalid guild member limit")]
+    InvalidMemberLimit,
+
+    #[error("Player is already in a guild")]
+    AlreadyInGuild,
+
+    #[error("Player is not in a guild")]
+    NotInGuild,
+
+    #[error("Guild not found")]
+    GuildNotFound,
+
+    #[error("Guild is full")]
+    GuildFull,
+
+    #[error("Guild is invite-only")]
+    InviteRequired,
+
+    #[error("Only the guild leader can do this")]
+    NotGuildLeader,
+
+    #[error("Player is already a guild member")]
+    AlreadyGuildMember,
+
+    #[error("Player is not a guild member")]
+    NotGuildMember,
+
+    #[error("Use LeaveGuild to leave your own guild")]
+    CannotKickSelf,
+
+    #[error("Cannot revoke the last admin")]
+    LastAdmin,
+
+    #[error("Cannot transfer admin to yourself")]
+    CannotTransferToSelf,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill_stats() -> PlayerSeasonStats {
+        PlayerSeasonStats {
+            total_xp: 1_000,
+            prediction_score: 200,
+            meme_score: 100,
+            ..PlayerSeasonStats::default()
+        }
+    }
+
+    #[test]
+    fn skill_score_weights_categories() {
+        let stats = skill_stats();
+        assert_eq!(SkillIndexConfig::default().score(&stats, 0), 1_000);
+
+        let config = SkillIndexConfig {
+            prediction_weight: 200,
+            meme_weight: 0,
+            other_weight: 50,
+            ..SkillIndexConfig::default()
+        };
+        // 200 * 2 + 100 * 0 + 700 uncategorized * 0.5
+        assert_eq!(config.score(&stats, 0), 750);
+    }
+
+    #[test]
+    fn skill_score_decays_by_half_life() {
+        let config = SkillIndexConfig {
+            decay_half_life: Some(100),
+            ..SkillIndexConfig::default()
+        };
+        let stats = PlayerSeasonStats {
+            last_active_at: 1_000,
+            ..skill_stats()
+        };
+        assert_eq!(config.score(&stats, 900), 1_000);
+        assert_eq!(config.score(&stats, 1_000), 1_000);
+        assert_eq!(config.score(&stats, 1_050), 750);
+        assert_eq!(config.score(&stats, 1_100), 500);
+        assert_eq!(config.score(&stats, 1_150), 375);
+        assert_eq!(config.score(&stats, 1_000 + 100 * 64), 0);
+    }
+
+    #[test]
+    fn skill_tier_is_highest_reached() {
+        let config = SkillIndexConfig::default();
+        assert_eq!(config.tier_for(0).as_deref(), Some("Bronze"));
+        assert_eq!(config.tier_for(999).as_deref(), Some("Bronze"));
+        assert_eq!(config.tier_for(1_000).as_deref(), Some("Silver"));
+        assert_eq!(config.tier_for(u64::MAX).as_deref(), Some("Legendary"));
+
+        let mut unordered = config.clone();
+        unordered.tiers.reverse();
+        assert_eq!(unordered.tier_for(5_000).as_deref(), Some("Gold"));
+
+        unordered.tiers.retain(|tier| tier.min_score > 0);
+        assert_eq!(unordered.tier_for(10), None);
+    }
+
+    fn template() -> QuestTemplate {
+        QuestTemplate {
+            id: 1,
+            name: "Typing streak".to_string(),
+            title: "Type {target} words".to_string(),
+            description: "Finish {target} words in {mode} mode for {reward} XP".to_string(),
+            requirements_text: "{target} words".to_string(),
+            category: QuestCategory::Typing,
+            metric: RequirementMetric::TotalXp,
+            min_target: 10,
+            max_target: 100,
+            min_reward_xp: 5,
+            max_reward_xp: 50,
+            created_by: "admin".to_string(),
+            created_at: 0,
+            active: true,
+        }
+    }
+
+    fn instance(params: Vec<TemplateParam>) -> QuestInstance {
+        QuestInstance {
+            template_id: 1,
+            target: 20,
+            reward_xp: 15,
+            params,
+            starts_at: None,
+            ends_at: None,
+            max_completions: None,
+            prompt_hash: None,
+        }
+    }
+
+    #[test]
+    fn quest_template_fills_every_slot() {
+        let template = template();
+        let instance = instance(vec![TemplateParam {
+            name: "mode".to_string(),
+            value: "sprint".to_string(),
+        }]);
+        assert_eq!(template.fill(&template.title, &instance).as_deref(), Some("Type 20 words"));
+        assert_eq!(
+            template.fill(&template.description, &instance).as_deref(),
+            Some("Finish 20 words in sprint mode for 15 XP"),
+        );
+    }
+
+    #[test]
+    fn quest_template_rejects_unfilled_slots() {
+        let template = template();
+        assert_eq!(template.fill(&template.description, &instance(Vec::new())), None);
+    }
+
+    #[test]
+    fn quest_template_bounds_must_be_ordered() {
+        assert!(template().bounds_valid());
+        assert!(!QuestTemplate { min_target: 0, ..template() }.bounds_valid());
+        assert!(!QuestTemplate { max_target: 5, ..template() }.bounds_valid());
+        assert!(!QuestTemplate { max_reward_xp: 1, ..template() }.bounds_valid());
+    }
+
+    fn tier(max_rank: Option<u32>, max_percentile: Option<u32>) -> RewardTier {
+        RewardTier {
+            max_rank,
+            max_percentile,
+            amount: Amount::from_tokens(1),
+        }
+    }
+
+    #[test]
+    fn reward_tier_covers_top_ranks() {
+        let top_three = tier(Some(3), None);
+        assert!(top_three.covers(1, 100));
+        assert!(top_three.covers(3, 100));
+        assert!(!top_three.covers(4, 100));
+    }
+
+    #[test]
+    fn reward_tier_covers_top_percentile() {
+        let top_ten_percent = tier(None, Some(10));
+        assert!(top_ten_percent.covers(10, 100));
+        assert!(!top_ten_percent.covers(11, 100));
+        // Fewer than ten players leave no one in the top ten percent
+        assert!(!top_ten_percent.covers(1, 9));
+        assert!(top_ten_percent.covers(1, 10));
+        // Large seasons do not overflow
+        assert!(top_ten_percent.covers(u32::MAX / 10, u32::MAX));
+    }
+
+    #[test]
+    fn reward_tier_covers_either_bound() {
+        let tier = tier(Some(5), Some(1));
+        assert!(tier.covers(5, 100));
+        assert!(!tier.covers(6, 100));
+        assert!(tier.covers(10, 1_000));
+        assert_eq!(tier.last_rank(100), 5);
+        assert_eq!(tier.last_rank(1_000), 10);
+        assert_eq!(tier.last_rank(3), 3);
+    }
+
+    #[test]
+    fn reward_tier_needs_bounds_in_range() {
+        assert!(!tier(None, None).is_valid());
+        assert!(!tier(Some(0), None).is_valid());
+        assert!(!tier(None, Some(0)).is_valid());
+        assert!(!tier(None, Some(101)).is_valid());
+        assert!(tier(Some(1), Some(100)).is_valid());
+    }
+
+    #[test]
+    fn payouts_shrink_to_fit_the_prize_pool() {
+        let mut season = Season {
+            id: 1,
+            title: "Season".to_string(),
+            description: String::new(),
+            start_time: 0,
+            end_time: 10,
+            active: false,
+            theme: None,
+            grace_period: 0,
+            finalized_at: Some(10),
+            archived: false,
+            rollover: None,
+            reward_tiers: vec![tier(None, Some(10))],
+            prize_pool: Amount::from_tokens(5),
+            ranked_players: 50,
+            payout_ppm: FULL_PAYOUT_PPM,
+            ranks_snapshotted: 0,
+            snapshot_cursor: None,
+            previous_season: None,
+            skill_config: SkillIndexConfig::default(),
+            limits: ActionLimits::default(),
+        };
+        season.scale_payouts_to_prize_pool();
+        assert_eq!(season.reward_for_rank(5), Some(Amount::from_tokens(1)));
+
+        // Twice the players share the same pool
+        season.ranked_players = 100;
+        season.scale_payouts_to_prize_pool();
+        assert_eq!(season.payout_ppm, FULL_PAYOUT_PPM / 2);
+        assert_eq!(season.reward_for_rank(10), Some(Amount::from_millis(500)));
+        assert!(season.total_payout(100).saturating_div(2) <= season.prize_pool);
+    }
 }
@@ -8,11 +8,11 @@ use std::sync::Arc;
 use async_graphql::{EmptySubscription, Object, Schema};
 use linera_sdk::{
     graphql::GraphQLMutationRoot as _,
-    linera_base_types::WithServiceAbi,
+    linera_base_types::{Amount, ApplicationId, WithServiceAbi},
     views::{RootView, View},
     Service, ServiceRuntime,
 };
-use arcade_nexus::{ArcadeNexusAbi, ArcadeSkillIndex, Operation, PlayerSeasonStats, Quest, QuestProgress, Season};
+use arcade_nexus::{Achievement, AchievementKind, ActivityEntry, ArcadeNexusAbi, ArcadeSkillIndex, CareerProfile, Guild, GuildSeasonStats, LeaderboardPage, ModerationRecord, Operation, PlayerActivity, PlayerExportRow, PlayerSeasonStats, Quest, QuestProgressReport, QuestTemplate, RankedPlayer, RegisteredGame, RequirementMetric, RewardClaim, Role, RoleMember, Season, SeasonExport, SeasonPhase, XpBucket, MAX_LEADERBOARD_PAGE, MAX_TIMELINE_DAYS};
 use state::ArcadeNexusState;
 
 /// The ArcadeNexus service.
@@ -42,7 +42,10 @@ impl Service for ArcadeNexusService {
 
     async fn handle_query(&self, request: Self::Query) -> Self::QueryResponse {
         let schema = Schema::build(
-            QueryRoot { state: self.state.clone() },
+            QueryRoot {
+                state: self.state.clone(),
+                runtime: self.runtime.clone(),
+            },
             Operation::mutation_root(self.runtime.clone()),
             EmptySubscription,
         )
@@ -54,18 +57,13 @@ impl Service for ArcadeNexusService {
 /// GraphQL query root.
 struct QueryRoot {
     state: Arc<ArcadeNexusState>,
+    runtime: Arc<ServiceRuntime<ArcadeNexusService>>,
 }
 
-/// Calculate rank hint based on total XP
-fn calculate_rank_hint(total_xp: u64) -> String {
-    if total_xp >= 10000 {
-        "Legendary".to_string()
-    } else if total_xp >= 5000 {
-        "Gold".to_string()
-    } else if total_xp >= 1000 {
-        "Silver".to_string()
-    } else {
-        "Bronze".to_string()
+impl QueryRoot {
+    /// Current time in seconds since epoch.
+    fn now_seconds(&self) -> i64 {
+        (self.runtime.system_time().micros() / 1_000_000) as i64
     }
 }
 
@@ -86,17 +84,95 @@ impl QueryRoot {
         self.state.get_season(id).await
     }
 
+    /// Get the current lifecycle phase of a season.
+    async fn season_phase(&self, id: u64) -> Option<SeasonPhase> {
+        let now = self.now_seconds();
+        self.state.get_season(id).await.map(|s| s.phase(now))
+    }
+
+    /// Get all seasons currently in the given lifecycle phase (newest first).
+    async fn seasons_in_phase(&self, phase: SeasonPhase) -> Vec<Season> {
+        let now = self.now_seconds();
+        self.state
+            .get_all_seasons()
+            .await
+            .into_iter()
+            .filter(|s| s.phase(now) == phase)
+            .collect()
+    }
+
     /// Get player stats for a season.
     async fn player_season_stats(&self, owner: String, season_id: u64) -> PlayerSeasonStats {
         self.state.get_player_stats(&owner, season_id).await
     }
 
-    /// Get leaderboard for a season (top N players by XP).
+    /// Get leaderboard for a season (top N players by XP, at most `MAX_LEADERBOARD_PAGE`).
     async fn leaderboard(&self, season_id: u64, limit: Option<i32>) -> Vec<PlayerSeasonStats> {
-        let limit = limit.unwrap_or(50) as usize;
+        let limit = (limit.unwrap_or(50).max(0) as usize).min(MAX_LEADERBOARD_PAGE);
         self.state.get_leaderboard(season_id, limit).await
     }
 
+    /// Get a page of a season leaderboard, starting after the given cursor
+    /// (at most `MAX_LEADERBOARD_PAGE` players).
+    async fn leaderboard_page(
+        &self,
+        season_id: u64,
+        after: Option<String>,
+        limit: Option<i32>,
+    ) -> async_graphql::Result<LeaderboardPage> {
+        let limit = (limit.unwrap_or(50).max(0) as usize).min(MAX_LEADERBOARD_PAGE);
+        let entries = self
+            .state
+            .get_leaderboard_page(season_id, after.as_deref(), limit)
+            .await
+            .ok_or_else(|| async_graphql::Error::new("Invalid cursor"))?;
+        let next_cursor = if entries.len() == limit {
+            entries.last().map(ArcadeNexusState::encode_cursor)
+        } else {
+            None
+        };
+        Ok(LeaderboardPage { entries, next_cursor })
+    }
+
+    /// Get a player's 1-based rank in a season (None if they have no XP record).
+    async fn player_rank(&self, owner: String, season_id: u64) -> Option<u32> {
+        self.state.get_player_rank(&owner, season_id).await
+    }
+
+    /// Get the players ranked within `radius` places of a player (default 5).
+    async fn players_around(
+        &self,
+        owner: String,
+        season_id: u64,
+        radius: Option<i32>,
+    ) -> Vec<RankedPlayer> {
+        let radius = (radius.unwrap_or(5).max(0) as usize).min(MAX_LEADERBOARD_PAGE / 2);
+        self.state
+            .get_players_around(&owner, season_id, radius)
+            .await
+            .into_iter()
+            .map(|(rank, stats)| RankedPlayer { rank, stats })
+            .collect()
+    }
+
+    /// Get the reward a player would receive for a finalized season.
+    async fn season_reward(&self, owner: String, season_id: u64) -> Option<Amount> {
+        let season = self.state.get_season(season_id).await?;
+        season.finalized_at?;
+        let rank = self.state.get_player_stats(&owner, season_id).await.rank_snapshot?;
+        season.reward_for_rank(rank)
+    }
+
+    /// Get the reward a player has claimed for a season, if any.
+    async fn reward_claim(&self, owner: String, season_id: u64) -> Option<RewardClaim> {
+        self.state.get_reward_claim(&owner, season_id).await
+    }
+
+    /// Get all rewards paid out for a season, best rank first.
+    async fn season_reward_claims(&self, season_id: u64) -> Vec<RewardClaim> {
+        self.state.get_season_reward_claims(season_id).await
+    }
+
     /// Get all quests for a season.
     async fn quests(&self, season_id: u64) -> Vec<Quest> {
         self.state.get_season_quests(season_id).await
@@ -107,36 +183,260 @@ impl QueryRoot {
         self.state.get_quest(id).await
     }
 
-    /// Get player's quest progress for a season.
-    async fn player_quests(&self, owner: String, season_id: u64) -> Vec<QuestProgress> {
-        self.state.get_player_quests(&owner, season_id).await
+    /// Check whether a player currently meets a quest's requirement.
+    async fn meets_quest_requirement(&self, owner: String, quest_id: u64) -> bool {
+        let quest = match self.state.get_quest(quest_id).await {
+            Some(q) => q,
+            None => return false,
+        };
+        let stats = self.state.get_player_stats(&owner, quest.season_id).await;
+        let progress = self.state.get_quest_progress(&owner, quest_id).await;
+        quest.requirement.is_none_or(|r| r.is_met(&stats, progress.baseline))
     }
 
-    /// Get a player's Arcade Skill Index for a season.
-    async fn skill_index(&self, owner: String, season_id: u64) -> ArcadeSkillIndex {
+    /// Get player's progress on every quest of a season, with percentages.
+    async fn player_quests(&self, owner: String, season_id: u64) -> Vec<QuestProgressReport> {
         let stats = self.state.get_player_stats(&owner, season_id).await;
-        
-        // Calculate overall score (weighted sum)
-        let overall_score = stats.total_xp
-            + stats.prediction_score
-            + stats.meme_score
-            + stats.typing_score
-            + stats.life_score;
-        
-        // Calculate rank hint
-        let rank_hint = Some(calculate_rank_hint(stats.total_xp));
-        
-        ArcadeSkillIndex {
-            owner: owner.clone(),
-            season_id,
-            total_xp: stats.total_xp,
-            overall_score,
-            rank_hint,
+        let mut reports = Vec::new();
+        for quest in self.state.get_season_quests(season_id).await {
+            let progress = self.state.get_quest_progress(&owner, quest.id).await;
+            let current = quest
+                .requirement
+                .map_or(progress.progress, |r| stats.metric_value(r.metric));
+            let target = quest.requirement.map(|r| r.target);
+            let gained = current.saturating_sub(progress.baseline);
+            let percent = match target {
+                _ if progress.completed && quest.cooldown.is_none() => 100,
+                Some(0) => 100,
+                Some(target) => (gained.min(target) * 100 / target) as u32,
+                None if progress.completed => 100,
+                None => 0,
+            };
+            reports.push(QuestProgressReport {
+                progress,
+                current,
+                target,
+                percent,
+            });
         }
+        reports
+    }
+
+    /// Get all quest templates.
+    async fn quest_templates(&self) -> Vec<QuestTemplate> {
+        self.state.get_all_quest_templates().await
+    }
+
+    /// Get a specific quest template by ID.
+    async fn quest_template(&self, id: u64) -> Option<QuestTemplate> {
+        self.state.get_quest_template(id).await
+    }
+
+    /// Export a season's aggregate stats and its top players' metrics in a
+    /// compact form for off-chain quest generators (at most
+    /// `MAX_LEADERBOARD_PAGE` rows).
+    async fn season_export(&self, season_id: u64, limit: Option<i32>) -> Option<SeasonExport> {
+        let season = self.state.get_season(season_id).await?;
+        let limit = (limit.unwrap_or(100).max(0) as usize).min(MAX_LEADERBOARD_PAGE);
+        let rows = self
+            .state
+            .get_leaderboard(season_id, limit)
+            .await
+            .into_iter()
+            .map(|stats| PlayerExportRow {
+                values: RequirementMetric::ALL
+                    .iter()
+                    .map(|metric| stats.metric_value(*metric))
+                    .collect(),
+                owner: stats.owner,
+                completed_quests: stats.completed_quests,
+            })
+            .collect();
+
+        let quests = self.state.get_season_quests(season_id).await;
+        Some(SeasonExport {
+            season_id,
+            theme: season.theme,
+            players: self.state.get_ranked_count(season_id).await,
+            columns: RequirementMetric::ALL.to_vec(),
+            totals: self.state.get_metric_totals(season_id).await,
+            quests: quests.len() as u32,
+            quest_completions: quests.iter().map(|q| q.completions).sum(),
+            rows,
+        })
+    }
+
+    /// Get a player's Arcade Skill Index for a season, as last persisted.
+    async fn skill_index(&self, owner: String, season_id: u64) -> ArcadeSkillIndex {
+        self.state
+            .get_skill_index(&owner, season_id)
+            .await
+            .unwrap_or(ArcadeSkillIndex {
+                owner,
+                season_id,
+                ..Default::default()
+            })
+    }
+
+    /// Get the top players of a season by Arcade Skill Index (default 50).
+    ///
+    /// Scores are as of each index's `computed_at`: decay is applied when a
+    /// player acts or when `refreshSkillIndex`/`refreshSkillIndexes` runs, so
+    /// idle players keep their last score until then.
+    async fn skill_leaderboard(&self, season_id: u64, limit: Option<i32>) -> Vec<ArcadeSkillIndex> {
+        let limit = limit.unwrap_or(50).max(0) as usize;
+        self.state.get_skill_leaderboard(season_id, limit).await
+    }
+
+    /// Get a player's lifetime career record across finalized seasons.
+    async fn career(&self, owner: String) -> CareerProfile {
+        self.state.get_career(&owner).await
+    }
+
+    /// Get the achievements a player has earned.
+    async fn achievements(&self, owner: String) -> Vec<Achievement> {
+        self.state.get_career(&owner).await.achievements
     }
 
-    /// Get admin chain ID.
+    /// Get the owners holding an achievement.
+    async fn achievement_holders(&self, kind: AchievementKind) -> Vec<String> {
+        self.state.get_achievement_holders(kind).await
+    }
+
+    /// Get all guilds.
+    async fn guilds(&self) -> Vec<Guild> {
+        self.state.get_all_guilds().await
+    }
+
+    /// Get a guild by ID.
+    async fn guild(&self, id: u64) -> Option<Guild> {
+        self.state.get_guild(id).await
+    }
+
+    /// Get the guild a player belongs to.
+    async fn guild_of(&self, owner: String) -> Option<Guild> {
+        let guild_id = self.state.get_guild_id_of(&owner).await?;
+        self.state.get_guild(guild_id).await
+    }
+
+    /// Get the IDs of the guilds a player has been invited to.
+    async fn guild_invites(&self, owner: String) -> Vec<u64> {
+        self.state.get_guild_invites(&owner).await
+    }
+
+    /// Get a guild's pooled XP for a season.
+    async fn guild_season_stats(&self, guild_id: u64, season_id: u64) -> GuildSeasonStats {
+        self.state.get_guild_season_stats(guild_id, season_id).await
+    }
+
+    /// Get guild leaderboard for a season (top N guilds by pooled XP).
+    async fn guild_leaderboard(&self, season_id: u64, limit: Option<i32>) -> Vec<GuildSeasonStats> {
+        let limit = limit.unwrap_or(50).max(0) as usize;
+        self.state.get_guild_leaderboard(season_id, limit).await
+    }
+
+    /// Get all game applications allowed to report XP.
+    async fn registered_games(&self) -> Vec<RegisteredGame> {
+        self.state.get_registered_games().await
+    }
+
+    /// Get a registered game by application ID.
+    async fn registered_game(&self, application_id: ApplicationId) -> Option<RegisteredGame> {
+        self.state.get_registered_game(&application_id).await
+    }
+
+    /// Get players with rejected actions in a season, most rejections first.
+    async fn moderation(&self, season_id: u64, min_rejections: Option<u32>) -> Vec<ModerationRecord> {
+        self.state
+            .get_moderation_records(season_id, min_rejections.unwrap_or(1))
+            .await
+    }
+
+    /// Get a player's rejected-action counters for a season.
+    async fn player_moderation(&self, owner: String, season_id: u64) -> ModerationRecord {
+        self.state.get_moderation_record(&owner, season_id).await
+    }
+
+    /// Get a player's XP-earning activity for the current day of a season.
+    async fn player_activity(&self, owner: String, season_id: u64) -> PlayerActivity {
+        self.state.get_player_activity(&owner, season_id).await
+    }
+
+    /// Get the global activity feed, newest first. Pass the ID of the last
+    /// entry received as `before` to fetch the next page.
+    async fn recent_activity(&self, before: Option<u64>, limit: Option<i32>) -> Vec<ActivityEntry> {
+        let limit = limit.unwrap_or(50).max(0) as usize;
+        self.state.get_recent_activity(before, limit).await
+    }
+
+    /// Get a player's activity timeline, newest first, optionally for one season.
+    async fn player_timeline(
+        &self,
+        owner: String,
+        season_id: Option<u64>,
+        before: Option<u64>,
+        limit: Option<i32>,
+    ) -> Vec<ActivityEntry> {
+        let limit = limit.unwrap_or(50).max(0) as usize;
+        self.state
+            .get_player_timeline(&owner, season_id, before, limit)
+            .await
+    }
+
+    /// Get the XP granted in a season per UTC day, from `from_day` (default:
+    /// the season's first day) until its end (or today, while it is still
+    /// running), at most `limit` days (default and maximum `MAX_TIMELINE_DAYS`).
+    async fn season_xp_timeline(
+        &self,
+        season_id: u64,
+        from_day: Option<u64>,
+        limit: Option<i32>,
+    ) -> Vec<XpBucket> {
+        let season = match self.state.get_season(season_id).await {
+            Some(s) => s,
+            None => return Vec::new(),
+        };
+        let limit = limit.map_or(MAX_TIMELINE_DAYS, |limit| limit.max(0) as usize).min(MAX_TIMELINE_DAYS);
+        let first_day = (season.start_time.max(0) / 86_400) as u64;
+        let first_day = from_day.map_or(first_day, |day| day.max(first_day));
+        let last_day = (season.end_time.min(self.now_seconds()).max(0) / 86_400) as u64;
+        self.state.get_xp_timeline(season_id, first_day, last_day, limit).await
+    }
+
+    /// Get every role assignment (for auditing who can do what).
+    async fn roles(&self) -> Vec<RoleMember> {
+        self.state.get_all_roles().await
+    }
+
+    /// Get the role assignment of a specific account.
+    async fn role_member(&self, member: String) -> Option<RoleMember> {
+        self.state.get_role_member(&member).await
+    }
+
+    /// Check whether an account holds a role (admins hold every role).
+    async fn has_role(&self, member: String, role: Role) -> bool {
+        self.state.has_role(&member, role).await
+    }
+
+    /// Get the account owners holding the Admin role.
+    async fn admins(&self) -> Vec<String> {
+        self.state.get_admins().await
+    }
+
+    /// Get the admin account, preferring the creator who granted themselves
+    /// the role at instantiation (kept for clients that predate roles).
     async fn admin(&self) -> Option<String> {
-        self.state.get_admin()
+        let admins: Vec<RoleMember> = self
+            .state
+            .get_all_roles()
+            .await
+            .into_iter()
+            .filter(|m| m.roles.contains(&Role::Admin))
+            .collect();
+        admins
+            .iter()
+            .find(|m| m.updated_by == m.member)
+            .or_else(|| admins.first())
+            .map(|m| m.member.clone())
     }
 }
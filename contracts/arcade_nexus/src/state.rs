@@ -1,7 +1,10 @@
 //! ArcadeNexus contract state.
 
-use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext};
-use arcade_nexus::{PlayerSeasonStats, Quest, QuestProgress, Season};
+use linera_sdk::{
+    linera_base_types::ApplicationId,
+    views::{linera_views, ByteMapView, LogView, MapView, RegisterView, RootView, ViewError, ViewStorageContext},
+};
+use arcade_nexus::{AchievementKind, ActivityEntry, ActivityKind, ArcadeSkillIndex, CareerProfile, Guild, GuildSeasonStats, ModerationRecord, PlayerActivity, PlayerSeasonStats, Quest, QuestProgress, QuestTemplate, RegisteredGame, RequirementMetric, RewardClaim, Role, RoleMember, Season, SeasonPhase, XpBucket};
 
 /// The application state stored on-chain.
 #[derive(RootView)]
@@ -18,15 +21,88 @@ pub struct ArcadeNexusState {
     
     /// Player stats keyed by "owner:season_id".
     pub player_stats: MapView<String, PlayerSeasonStats>,
+
+    /// Today's XP-earning activity keyed by "owner:season_id".
+    pub player_activity: MapView<String, PlayerActivity>,
+
+    /// Rejected-action counters, see `ArcadeNexusState::season_key`.
+    pub moderation: ByteMapView<ModerationRecord>,
+
+    /// Per-season ranking index, see `ArcadeNexusState::rank_key`.
+    pub season_rankings: ByteMapView<()>,
+
+    /// Number of ranked players under each XP prefix, see `ArcadeNexusState::count_key`.
+    pub season_rank_counts: ByteMapView<u64>,
+
+    /// Sum of each `RequirementMetric::ALL` value over a season's players, keyed by season ID.
+    pub season_metric_totals: MapView<u64, Vec<u64>>,
+
+    /// Persisted Arcade Skill Index keyed by "owner:season_id".
+    pub skill_indices: MapView<String, ArcadeSkillIndex>,
+
+    /// Per-season skill index ranking, keyed like `season_rankings` by overall score.
+    pub skill_rankings: ByteMapView<()>,
     
     /// All quests, keyed by quest ID.
     pub quests: MapView<u64, Quest>,
+
+    /// Unretired quests with a requirement, see `ArcadeNexusState::metric_quest_key`.
+    pub quests_by_metric: ByteMapView<()>,
     
     /// Quest progress keyed by "owner:quest_id".
     pub quest_progress: MapView<String, QuestProgress>,
+
+    /// Counter for generating unique quest template IDs.
+    pub next_template_id: RegisterView<u64>,
+
+    /// Quest templates, keyed by template ID.
+    pub quest_templates: MapView<u64, QuestTemplate>,
+    
+    /// Lifetime career records, keyed by owner.
+    pub careers: MapView<String, CareerProfile>,
+
+    /// Most recently finalized season of each theme ("" for no theme), used
+    /// for achievement streaks.
+    pub last_finalized_seasons: MapView<String, u64>,
+
+    /// Counter for generating unique guild IDs.
+    pub next_guild_id: RegisterView<u64>,
+
+    /// All guilds, keyed by guild ID.
+    pub guilds: MapView<u64, Guild>,
+
+    /// Guild membership: owner to guild ID.
+    pub guild_members: MapView<String, u64>,
+
+    /// Pending guild invites valued by invite time, see `ArcadeNexusState::invite_key`.
+    pub guild_invites: ByteMapView<u64>,
+
+    /// Pending guild invites by guild: big-endian guild ID followed by owner.
+    pub guild_invitees: ByteMapView<()>,
+
+    /// Guild season XP keyed by big-endian guild ID and season ID.
+    pub guild_season_stats: ByteMapView<GuildSeasonStats>,
+
+    /// Per-season guild ranking, keyed like `season_rankings` with the guild ID as owner.
+    pub guild_rankings: ByteMapView<()>,
+
+    /// Paid season rewards, see `ArcadeNexusState::season_key`.
+    pub reward_claims: ByteMapView<RewardClaim>,
+
+    /// Append-only log of XP grants, quest completions and season transitions.
+    pub activity_log: LogView<ActivityEntry>,
+
+    /// Per-player index into `activity_log`, see `ArcadeNexusState::timeline_key`.
+    pub player_timelines: ByteMapView<()>,
+
+    /// Daily season XP keyed by "season_id:day".
+    pub xp_buckets: MapView<String, XpBucket>,
+
+    /// Role assignments, keyed by member account owner.
+    pub roles: MapView<String, RoleMember>,
     
-    /// Admin chain ID (creator of the contract).
-    pub admin: RegisterView<Option<String>>,
+    /// Game applications allowed to report XP, keyed by application ID.
+    pub registered_games: MapView<ApplicationId, RegisteredGame>,
 }
 
 impl ArcadeNexusState {
@@ -35,6 +111,96 @@ impl ArcadeNexusState {
         format!("{}:{}", owner, season_id)
     }
 
+    /// Make a ranking index key. Season ID and inverted total XP are stored
+    /// big-endian so keys sort by season, then by XP descending, then by owner.
+    fn rank_key(season_id: u64, total_xp: u64, owner: &str) -> Vec<u8> {
+        let mut key = season_id.to_be_bytes().to_vec();
+        key.extend_from_slice(&Self::rank_suffix(total_xp, owner));
+        key
+    }
+
+    /// The part of a ranking key that follows the season prefix.
+    fn rank_suffix(total_xp: u64, owner: &str) -> Vec<u8> {
+        let mut suffix = (u64::MAX - total_xp).to_be_bytes().to_vec();
+        suffix.extend_from_slice(owner.as_bytes());
+        suffix
+    }
+
+    /// Make a rank count key. Counts form a 256-ary tree over the big-endian
+    /// inverted XP of `rank_key`: the node of a byte prefix holds the number
+    /// of ranked players whose inverted XP starts with it. The prefix length
+    /// is stored first so each level of the tree can be scanned on its own.
+    fn count_key(season_id: u64, xp_prefix: &[u8]) -> Vec<u8> {
+        let mut key = season_id.to_be_bytes().to_vec();
+        key.push(xp_prefix.len() as u8);
+        key.extend_from_slice(xp_prefix);
+        key
+    }
+
+    /// Encode a leaderboard cursor pointing at a player's ranking entry.
+    pub fn encode_cursor(stats: &PlayerSeasonStats) -> String {
+        format!("{}:{}", stats.total_xp, stats.owner)
+    }
+
+    /// Decode a leaderboard cursor into a ranking key suffix.
+    fn decode_cursor(cursor: &str) -> Option<Vec<u8>> {
+        let (total_xp, owner) = cursor.split_once(':')?;
+        let total_xp = total_xp.parse::<u64>().ok()?;
+        Some(Self::rank_suffix(total_xp, owner))
+    }
+
+    /// Make a player timeline key. The log position is inverted and stored
+    /// big-endian so a player's entries sort newest first.
+    fn timeline_key(owner: &str, entry_id: u64) -> Vec<u8> {
+        let mut key = Self::owner_prefix(owner);
+        key.extend_from_slice(&(u64::MAX - entry_id).to_be_bytes());
+        key
+    }
+
+    /// Make a quest index key: big-endian season ID, requirement metric and
+    /// quest ID, so a season's quests on one metric share a prefix.
+    fn metric_quest_key(season_id: u64, metric: RequirementMetric, quest_id: u64) -> Vec<u8> {
+        let mut key = Self::metric_quest_prefix(season_id, metric);
+        key.extend_from_slice(&quest_id.to_be_bytes());
+        key
+    }
+
+    /// The part of a quest index key identifying the season and metric.
+    fn metric_quest_prefix(season_id: u64, metric: RequirementMetric) -> Vec<u8> {
+        let mut prefix = season_id.to_be_bytes().to_vec();
+        prefix.push(metric as u8);
+        prefix
+    }
+
+    /// Make a per-season key whose big-endian season ID prefix lets all
+    /// entries of a season be walked without scanning other seasons.
+    fn season_key(season_id: u64, owner: &str) -> Vec<u8> {
+        let mut key = season_id.to_be_bytes().to_vec();
+        key.extend_from_slice(owner.as_bytes());
+        key
+    }
+
+    /// Make a guild invite key, grouping a player's invites under their prefix.
+    fn invite_key(owner: &str, guild_id: u64) -> Vec<u8> {
+        let mut key = Self::owner_prefix(owner);
+        key.extend_from_slice(&guild_id.to_be_bytes());
+        key
+    }
+
+    /// Make a guild-first key from a guild ID and a season ID or owner.
+    fn guild_key(guild_id: u64, rest: &[u8]) -> Vec<u8> {
+        let mut key = guild_id.to_be_bytes().to_vec();
+        key.extend_from_slice(rest);
+        key
+    }
+
+    /// The part of owner-first keys identifying the player.
+    fn owner_prefix(owner: &str) -> Vec<u8> {
+        let mut prefix = owner.as_bytes().to_vec();
+        prefix.push(0);
+        prefix
+    }
+
     /// Make a quest progress key from owner and quest_id
     fn progress_key(owner: &str, quest_id: u64) -> String {
         format!("{}:{}", owner, quest_id)
@@ -54,14 +220,106 @@ impl ArcadeNexusState {
         id
     }
 
-    /// Get admin chain ID.
-    pub fn get_admin(&self) -> Option<String> {
-        self.admin.get().clone()
+    // ==================== Roles ====================
+
+    /// Get the role assignment of a member.
+    pub async fn get_role_member(&self, member: &str) -> Option<RoleMember> {
+        self.roles.get(member).await.ok().flatten()
+    }
+
+    /// Check whether a member holds a role (admins hold every role).
+    pub async fn has_role(&self, member: &str, role: Role) -> bool {
+        self.get_role_member(member)
+            .await
+            .map(|m| m.has_role(role))
+            .unwrap_or(false)
+    }
+
+    /// Grant a role to a member. Returns false if it was already held.
+    pub async fn grant_role(&mut self, member: &str, role: Role, updated_by: &str, now: u64) -> bool {
+        let mut entry = self.get_role_member(member).await.unwrap_or(RoleMember {
+            member: member.to_string(),
+            roles: Vec::new(),
+            updated_by: updated_by.to_string(),
+            updated_at: now,
+        });
+        if entry.roles.contains(&role) {
+            return false;
+        }
+        entry.roles.push(role);
+        entry.updated_by = updated_by.to_string();
+        entry.updated_at = now;
+        let _ = self.roles.insert(member, entry);
+        true
+    }
+
+    /// Revoke a role from a member. Returns false if it was not held.
+    pub async fn revoke_role(&mut self, member: &str, role: Role, updated_by: &str, now: u64) -> bool {
+        let mut entry = match self.get_role_member(member).await {
+            Some(e) if e.roles.contains(&role) => e,
+            _ => return false,
+        };
+        entry.roles.retain(|r| *r != role);
+        if entry.roles.is_empty() {
+            let _ = self.roles.remove(member);
+        } else {
+            entry.updated_by = updated_by.to_string();
+            entry.updated_at = now;
+            let _ = self.roles.insert(member, entry);
+        }
+        true
+    }
+
+    /// Get all role assignments.
+    pub async fn get_all_roles(&self) -> Vec<RoleMember> {
+        let mut members = Vec::new();
+        let keys: Vec<String> = self.roles.indices().await.unwrap_or_default();
+        for key in keys {
+            if let Some(member) = self.roles.get(&key).await.ok().flatten() {
+                members.push(member);
+            }
+        }
+        members
     }
 
-    /// Set admin chain ID.
-    pub fn set_admin(&mut self, admin: String) {
-        self.admin.set(Some(admin));
+    /// Get the account owners holding the Admin role.
+    pub async fn get_admins(&self) -> Vec<String> {
+        self.get_all_roles()
+            .await
+            .into_iter()
+            .filter(|m| m.roles.contains(&Role::Admin))
+            .map(|m| m.member)
+            .collect()
+    }
+
+    // ==================== Game Registry ====================
+
+    /// Get a registered game by application ID.
+    pub async fn get_registered_game(&self, application_id: &ApplicationId) -> Option<RegisteredGame> {
+        self.registered_games.get(application_id).await.ok().flatten()
+    }
+
+    /// Save a registered game.
+    pub async fn save_registered_game(&mut self, game: RegisteredGame) {
+        let id = game.application_id;
+        let _ = self.registered_games.insert(&id, game);
+    }
+
+    /// Remove a game from the registry.
+    pub async fn remove_registered_game(&mut self, application_id: &ApplicationId) {
+        let _ = self.registered_games.remove(application_id);
+    }
+
+    /// Get all registered games.
+    pub async fn get_registered_games(&self) -> Vec<RegisteredGame> {
+        let mut games = Vec::new();
+        let keys: Vec<ApplicationId> = self.registered_games.indices().await.unwrap_or_default();
+        for key in keys {
+            if let Some(game) = self.registered_games.get(&key).await.ok().flatten() {
+                games.push(game);
+            }
+        }
+        games
     }
 
     // ==================== Seasons ====================
@@ -100,6 +358,24 @@ impl ArcadeNexusState {
             .collect()
     }
 
+    /// Get the IDs of the seasons that are live at the given time.
+    pub async fn get_live_season_ids(&self, now_seconds: i64) -> Vec<u64> {
+        self.get_active_seasons()
+            .await
+            .into_iter()
+            .filter(|s| s.phase(now_seconds) == SeasonPhase::Live)
+            .map(|s| s.id)
+            .collect()
+    }
+
+    /// Check whether an open season of the same theme overlaps the given window.
+    pub async fn has_overlapping_season(&self, theme: &Option<String>, start_time: i64, end_time: i64) -> bool {
+        self.get_active_seasons()
+            .await
+            .iter()
+            .any(|s| &s.theme == theme && s.overlaps(start_time, end_time))
+    }
+
     // ==================== Player Stats ====================
 
     /// Get player stats for a season.
@@ -117,33 +393,571 @@ impl ArcadeNexusState {
             })
     }
 
-    /// Save player stats.
+    /// Save player stats and keep the season ranking index and metric totals in sync.
     pub async fn save_player_stats(&mut self, stats: PlayerSeasonStats) {
         let key = Self::stats_key(&stats.owner, stats.season_id);
+        let previous = self.player_stats.get(&key).await.ok().flatten();
+        self.update_metric_totals(previous.as_ref(), &stats).await;
+        if let Some(previous) = &previous {
+            self.season_rankings.remove(Self::rank_key(
+                previous.season_id,
+                previous.total_xp,
+                &previous.owner,
+            ));
+        }
+        self.season_rankings.insert(
+            Self::rank_key(stats.season_id, stats.total_xp, &stats.owner),
+            (),
+        );
+        self.update_rank_counts(stats.season_id, previous.map(|p| p.total_xp), stats.total_xp)
+            .await;
         let _ = self.player_stats.insert(&key, stats);
     }
 
-    /// Get all player stats for a season (for leaderboard).
-    pub async fn get_leaderboard(&self, season_id: u64, limit: usize) -> Vec<PlayerSeasonStats> {
+    /// Replace a player's `previous` metric values (None for a new player) by
+    /// those of `stats` in the season's running totals.
+    async fn update_metric_totals(&mut self, previous: Option<&PlayerSeasonStats>, stats: &PlayerSeasonStats) {
+        let mut totals = self.get_metric_totals(stats.season_id).await;
+        for (total, metric) in totals.iter_mut().zip(RequirementMetric::ALL) {
+            let old = previous.map_or(0, |previous| previous.metric_value(metric));
+            *total = total.saturating_sub(old).saturating_add(stats.metric_value(metric));
+        }
+        let _ = self.season_metric_totals.insert(&stats.season_id, totals);
+    }
+
+    /// Get the sum of each `RequirementMetric::ALL` value over a season's players.
+    pub async fn get_metric_totals(&self, season_id: u64) -> Vec<u64> {
+        self.season_metric_totals
+            .get(&season_id)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| vec![0; RequirementMetric::ALL.len()])
+    }
+
+    /// Move a player from the rank count nodes of `previous` XP (None for a
+    /// new player) to those of `total_xp`. Shared prefixes are left alone.
+    async fn update_rank_counts(&mut self, season_id: u64, previous: Option<u64>, total_xp: u64) {
+        let new = (u64::MAX - total_xp).to_be_bytes();
+        let old = previous.map(|xp| (u64::MAX - xp).to_be_bytes());
+        for len in 1..=new.len() {
+            if let Some(old) = &old {
+                if old[..len] == new[..len] {
+                    continue;
+                }
+                let key = Self::count_key(season_id, &old[..len]);
+                let count = self.season_rank_counts.get(&key).await.ok().flatten().unwrap_or(0);
+                if count <= 1 {
+                    self.season_rank_counts.remove(key);
+                } else {
+                    self.season_rank_counts.insert(key, count - 1);
+                }
+            }
+            let key = Self::count_key(season_id, &new[..len]);
+            let count = self.season_rank_counts.get(&key).await.ok().flatten().unwrap_or(0);
+            self.season_rank_counts.insert(key, count + 1);
+        }
+    }
+
+    /// Sum the rank counts of the nodes one level below `xp_prefix` whose
+    /// next byte is smaller than `below` (all of them if None).
+    async fn count_ranked_below(&self, season_id: u64, xp_prefix: &[u8], below: Option<u8>) -> u64 {
+        // Keys of the child level, up to their last byte
+        let mut prefix = season_id.to_be_bytes().to_vec();
+        prefix.push(xp_prefix.len() as u8 + 1);
+        prefix.extend_from_slice(xp_prefix);
+
+        let mut total = 0;
+        let _ = self
+            .season_rank_counts
+            .for_each_key_value_while(
+                |suffix, count| {
+                    if below.is_some_and(|below| suffix[0] >= below) {
+                        return Ok::<_, ViewError>(false);
+                    }
+                    total += *count;
+                    Ok(true)
+                },
+                prefix,
+            )
+            .await;
+        total
+    }
+
+    /// Number of players with stats in a season.
+    pub async fn get_ranked_count(&self, season_id: u64) -> u32 {
+        self.count_ranked_below(season_id, &[], None).await as u32
+    }
+
+    /// Check whether a player has any stats recorded for a season.
+    pub async fn has_player_stats(&self, owner: &str, season_id: u64) -> bool {
+        let key = Self::stats_key(owner, season_id);
+        self.player_stats.get(&key).await.ok().flatten().is_some()
+    }
+
+    /// Walk a season's ranking index in order and return the owners of up to `limit`
+    /// entries, skipping the first `offset` entries and every entry up to and
+    /// including the key suffix `after`.
+    async fn ranked_owners(
+        index: &ByteMapView<()>,
+        season_id: u64,
+        after: Option<Vec<u8>>,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<String> {
+        let mut owners = Vec::new();
+        let mut skipped = 0;
+        let prefix = season_id.to_be_bytes().to_vec();
+        let _ = index
+            .for_each_key_while(
+                |suffix| {
+                    if owners.len() >= limit {
+                        return Ok::<_, ViewError>(false);
+                    }
+                    if after.as_deref().is_some_and(|after| suffix <= after) {
+                        return Ok(true);
+                    }
+                    if skipped < offset {
+                        skipped += 1;
+                        return Ok(true);
+                    }
+                    if let Ok(owner) = std::str::from_utf8(&suffix[8..]) {
+                        owners.push(owner.to_string());
+                    }
+                    Ok(true)
+                },
+                prefix,
+            )
+            .await;
+        owners
+    }
+
+    /// Load the stats of the given owners, preserving order.
+    async fn load_ranked_stats(&self, owners: Vec<String>, season_id: u64) -> Vec<PlayerSeasonStats> {
         let mut stats_list = Vec::new();
-        let keys: Vec<String> = self.player_stats.indices().await.unwrap_or_default();
-        
+        for owner in owners {
+            stats_list.push(self.get_player_stats(&owner, season_id).await);
+        }
+        stats_list
+    }
+
+    /// Get the top `limit` players of a season by total XP.
+    pub async fn get_leaderboard(&self, season_id: u64, limit: usize) -> Vec<PlayerSeasonStats> {
+        let owners = Self::ranked_owners(&self.season_rankings, season_id, None, 0, limit).await;
+        self.load_ranked_stats(owners, season_id).await
+    }
+
+    /// Get a page of a season's leaderboard starting after `cursor`.
+    ///
+    /// Returns `None` if the cursor is malformed.
+    pub async fn get_leaderboard_page(
+        &self,
+        season_id: u64,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Option<Vec<PlayerSeasonStats>> {
+        let after = match cursor {
+            Some(cursor) => Some(Self::decode_cursor(cursor)?),
+            None => None,
+        };
+        let owners = Self::ranked_owners(&self.season_rankings, season_id, after, 0, limit).await;
+        Some(self.load_ranked_stats(owners, season_id).await)
+    }
+
+    /// Get a player's 1-based rank in a season, if they have any stats.
+    ///
+    /// Players with more XP are counted from the rank count tree, one level
+    /// per byte of XP; only players tied on XP are walked in the index.
+    pub async fn get_player_rank(&self, owner: &str, season_id: u64) -> Option<u32> {
+        if !self.has_player_stats(owner, season_id).await {
+            return None;
+        }
+        let stats = self.get_player_stats(owner, season_id).await;
+        let inverted_xp = (u64::MAX - stats.total_xp).to_be_bytes();
+
+        let mut ahead = 0;
+        for len in 0..inverted_xp.len() {
+            ahead += self
+                .count_ranked_below(season_id, &inverted_xp[..len], Some(inverted_xp[len]))
+                .await;
+        }
+
+        let mut prefix = season_id.to_be_bytes().to_vec();
+        prefix.extend_from_slice(&inverted_xp);
+        let _ = self
+            .season_rankings
+            .for_each_key_while(
+                |tied_owner| {
+                    if tied_owner >= owner.as_bytes() {
+                        return Ok::<_, ViewError>(false);
+                    }
+                    ahead += 1;
+                    Ok(true)
+                },
+                prefix,
+            )
+            .await;
+        Some(ahead as u32 + 1)
+    }
+
+    /// Get the players ranked within `radius` places of a player, with ranks.
+    pub async fn get_players_around(
+        &self,
+        owner: &str,
+        season_id: u64,
+        radius: usize,
+    ) -> Vec<(u32, PlayerSeasonStats)> {
+        let rank = match self.get_player_rank(owner, season_id).await {
+            Some(rank) => rank as usize,
+            None => return Vec::new(),
+        };
+        let first = rank.saturating_sub(radius).max(1);
+        let count = rank + radius - first + 1;
+        let owners = Self::ranked_owners(&self.season_rankings, season_id, None, first - 1, count).await;
+        self.load_ranked_stats(owners, season_id)
+            .await
+            .into_iter()
+            .enumerate()
+            .map(|(i, stats)| ((first + i) as u32, stats))
+            .collect()
+    }
+
+    // ==================== Rate Limits ====================
+
+    /// Get a player's activity counters for a season.
+    pub async fn get_player_activity(&self, owner: &str, season_id: u64) -> PlayerActivity {
+        let key = Self::stats_key(owner, season_id);
+        self.player_activity
+            .get(&key)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(PlayerActivity {
+                owner: owner.to_string(),
+                season_id,
+                ..Default::default()
+            })
+    }
+
+    /// Save a player's activity counters.
+    pub async fn save_player_activity(&mut self, activity: PlayerActivity) {
+        let key = Self::stats_key(&activity.owner, activity.season_id);
+        let _ = self.player_activity.insert(&key, activity);
+    }
+
+    /// Get a player's rejected-action counters for a season.
+    pub async fn get_moderation_record(&self, owner: &str, season_id: u64) -> ModerationRecord {
+        let key = Self::season_key(season_id, owner);
+        self.moderation
+            .get(&key)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(ModerationRecord {
+                owner: owner.to_string(),
+                season_id,
+                ..Default::default()
+            })
+    }
+
+    /// Save a player's rejected-action counters.
+    pub async fn save_moderation_record(&mut self, record: ModerationRecord) {
+        let key = Self::season_key(record.season_id, &record.owner);
+        self.moderation.insert(key, record);
+    }
+
+    /// Get the moderation records of a season, most rejections first.
+    pub async fn get_moderation_records(&self, season_id: u64, min_rejections: u32) -> Vec<ModerationRecord> {
+        let mut records = Vec::new();
+        let _ = self
+            .moderation
+            .for_each_key_value_while(
+                |_, record| {
+                    if record.total() >= min_rejections {
+                        records.push(record.into_owned());
+                    }
+                    Ok::<_, ViewError>(true)
+                },
+                season_id.to_be_bytes().to_vec(),
+            )
+            .await;
+        records.sort_by_key(|record| std::cmp::Reverse(record.total()));
+        records
+    }
+
+    // ==================== Skill Index ====================
+
+    /// Get a player's persisted skill index for a season.
+    pub async fn get_skill_index(&self, owner: &str, season_id: u64) -> Option<ArcadeSkillIndex> {
+        let key = Self::stats_key(owner, season_id);
+        self.skill_indices.get(&key).await.ok().flatten()
+    }
+
+    /// Save a skill index and keep the season's skill ranking in sync.
+    pub async fn save_skill_index(&mut self, index: ArcadeSkillIndex) {
+        let key = Self::stats_key(&index.owner, index.season_id);
+        if let Some(previous) = self.skill_indices.get(&key).await.ok().flatten() {
+            self.skill_rankings.remove(Self::rank_key(
+                previous.season_id,
+                previous.overall_score,
+                &previous.owner,
+            ));
+        }
+        self.skill_rankings.insert(
+            Self::rank_key(index.season_id, index.overall_score, &index.owner),
+            (),
+        );
+        let _ = self.skill_indices.insert(&key, index);
+    }
+
+    /// Get the top `limit` players of a season by overall skill score.
+    pub async fn get_skill_leaderboard(&self, season_id: u64, limit: usize) -> Vec<ArcadeSkillIndex> {
+        let owners = Self::ranked_owners(&self.skill_rankings, season_id, None, 0, limit).await;
+        let mut indices = Vec::new();
+        for owner in owners {
+            if let Some(index) = self.get_skill_index(&owner, season_id).await {
+                indices.push(index);
+            }
+        }
+        indices
+    }
+
+    // ==================== Careers ====================
+
+    /// Get a player's career record.
+    pub async fn get_career(&self, owner: &str) -> CareerProfile {
+        self.careers
+            .get(owner)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(CareerProfile {
+                owner: owner.to_string(),
+                ..Default::default()
+            })
+    }
+
+    /// Get the most recently finalized season of a theme.
+    pub async fn get_last_finalized_season(&self, theme: &Option<String>) -> Option<u64> {
+        let key = theme.as_deref().unwrap_or_default();
+        self.last_finalized_seasons.get(key).await.ok().flatten()
+    }
+
+    /// Record a season as the most recently finalized of its theme.
+    pub async fn set_last_finalized_season(&mut self, theme: &Option<String>, season_id: u64) {
+        let key = theme.as_deref().unwrap_or_default();
+        let _ = self.last_finalized_seasons.insert(key, season_id);
+    }
+
+    /// Save a career record.
+    pub async fn save_career(&mut self, career: CareerProfile) {
+        let owner = career.owner.clone();
+        let _ = self.careers.insert(&owner, career);
+    }
+
+    /// Get the owners holding an achievement.
+    pub async fn get_achievement_holders(&self, kind: AchievementKind) -> Vec<String> {
+        let mut holders = Vec::new();
+        let keys: Vec<String> = self.careers.indices().await.unwrap_or_default();
         for key in keys {
-            if let Some(stats) = self.player_stats.get(&key).await.ok().flatten() {
-                if stats.season_id == season_id {
-                    stats_list.push(stats);
+            if let Some(career) = self.careers.get(&key).await.ok().flatten() {
+                if career.has_achievement(kind) {
+                    holders.push(career.owner);
                 }
             }
         }
-        
-        // Sort by total_xp descending
-        stats_list.sort_by(|a, b| b.total_xp.cmp(&a.total_xp));
-        
-        // Take top N
-        stats_list.truncate(limit);
+        holders
+    }
+
+    // ==================== Guilds ====================
+
+    /// Get the next guild ID and increment the counter.
+    pub async fn get_next_guild_id(&mut self) -> u64 {
+        let id = *self.next_guild_id.get();
+        self.next_guild_id.set(id + 1);
+        id
+    }
+
+    /// Get a guild by ID.
+    pub async fn get_guild(&self, guild_id: u64) -> Option<Guild> {
+        self.guilds.get(&guild_id).await.ok().flatten()
+    }
+
+    /// Save a guild.
+    pub async fn save_guild(&mut self, guild: Guild) {
+        let id = guild.id;
+        let _ = self.guilds.insert(&id, guild);
+    }
+
+    /// Remove a guild with its season stats, rankings and pending invites.
+    pub async fn remove_guild(&mut self, guild_id: u64) {
+        let _ = self.guilds.remove(&guild_id);
+
+        let mut season_stats = Vec::new();
+        let _ = self
+            .guild_season_stats
+            .for_each_key_value_while(
+                |_, stats| {
+                    season_stats.push(stats.into_owned());
+                    Ok::<_, ViewError>(true)
+                },
+                guild_id.to_be_bytes().to_vec(),
+            )
+            .await;
+        let guild = guild_id.to_string();
+        for stats in season_stats {
+            self.guild_rankings.remove(Self::rank_key(stats.season_id, stats.total_xp, &guild));
+            self.guild_season_stats.remove(Self::guild_key(guild_id, &stats.season_id.to_be_bytes()));
+        }
+
+        let mut invitees = Vec::new();
+        let _ = self
+            .guild_invitees
+            .for_each_key_while(
+                |owner| {
+                    if let Ok(owner) = std::str::from_utf8(owner) {
+                        invitees.push(owner.to_string());
+                    }
+                    Ok::<_, ViewError>(true)
+                },
+                guild_id.to_be_bytes().to_vec(),
+            )
+            .await;
+        for owner in invitees {
+            self.remove_guild_invite(&owner, guild_id).await;
+        }
+    }
+
+    /// Get all guilds.
+    pub async fn get_all_guilds(&self) -> Vec<Guild> {
+        let mut guilds = Vec::new();
+        let keys: Vec<u64> = self.guilds.indices().await.unwrap_or_default();
+        for key in keys {
+            if let Some(guild) = self.guilds.get(&key).await.ok().flatten() {
+                guilds.push(guild);
+            }
+        }
+        guilds
+    }
+
+    /// Get the ID of the guild a player belongs to.
+    pub async fn get_guild_id_of(&self, owner: &str) -> Option<u64> {
+        self.guild_members.get(owner).await.ok().flatten()
+    }
+
+    /// Record a player's guild membership.
+    pub async fn set_guild_member(&mut self, owner: &str, guild_id: u64) {
+        let _ = self.guild_members.insert(owner, guild_id);
+    }
+
+    /// Remove a player's guild membership.
+    pub async fn remove_guild_member(&mut self, owner: &str) {
+        let _ = self.guild_members.remove(owner);
+    }
+
+    /// Check whether a player has a pending invite to a guild.
+    pub async fn has_guild_invite(&self, owner: &str, guild_id: u64) -> bool {
+        let key = Self::invite_key(owner, guild_id);
+        self.guild_invites.get(&key).await.ok().flatten().is_some()
+    }
+
+    /// Record a pending guild invite.
+    pub async fn save_guild_invite(&mut self, owner: &str, guild_id: u64, now: u64) {
+        self.guild_invites.insert(Self::invite_key(owner, guild_id), now);
+        self.guild_invitees.insert(Self::guild_key(guild_id, owner.as_bytes()), ());
+    }
+
+    /// Remove a pending guild invite.
+    pub async fn remove_guild_invite(&mut self, owner: &str, guild_id: u64) {
+        self.guild_invites.remove(Self::invite_key(owner, guild_id));
+        self.guild_invitees.remove(Self::guild_key(guild_id, owner.as_bytes()));
+    }
+
+    /// Get the IDs of the guilds a player has pending invites to.
+    pub async fn get_guild_invites(&self, owner: &str) -> Vec<u64> {
+        let mut guild_ids = Vec::new();
+        let _ = self
+            .guild_invites
+            .for_each_key_while(
+                |suffix| {
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(&suffix[..8]);
+                    guild_ids.push(u64::from_be_bytes(bytes));
+                    Ok::<_, ViewError>(true)
+                },
+                Self::owner_prefix(owner),
+            )
+            .await;
+        guild_ids
+    }
+
+    /// Get a guild's pooled XP for a season.
+    pub async fn get_guild_season_stats(&self, guild_id: u64, season_id: u64) -> GuildSeasonStats {
+        let key = Self::guild_key(guild_id, &season_id.to_be_bytes());
+        self.guild_season_stats
+            .get(&key)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(GuildSeasonStats {
+                guild_id,
+                season_id,
+                ..Default::default()
+            })
+    }
+
+    /// Save a guild's season XP and keep the guild ranking in sync.
+    pub async fn save_guild_season_stats(&mut self, stats: GuildSeasonStats) {
+        let key = Self::guild_key(stats.guild_id, &stats.season_id.to_be_bytes());
+        let guild = stats.guild_id.to_string();
+        if let Some(previous) = self.guild_season_stats.get(&key).await.ok().flatten() {
+            self.guild_rankings.remove(Self::rank_key(previous.season_id, previous.total_xp, &guild));
+        }
+        self.guild_rankings.insert(Self::rank_key(stats.season_id, stats.total_xp, &guild), ());
+        self.guild_season_stats.insert(key, stats);
+    }
+
+    /// Get the top `limit` guilds of a season by pooled XP.
+    pub async fn get_guild_leaderboard(&self, season_id: u64, limit: usize) -> Vec<GuildSeasonStats> {
+        let guilds = Self::ranked_owners(&self.guild_rankings, season_id, None, 0, limit).await;
+        let mut stats_list = Vec::new();
+        for guild_id in guilds.iter().filter_map(|id| id.parse().ok()) {
+            stats_list.push(self.get_guild_season_stats(guild_id, season_id).await);
+        }
         stats_list
     }
 
+    // ==================== Rewards ====================
+
+    /// Get the reward a player claimed for a season, if any.
+    pub async fn get_reward_claim(&self, owner: &str, season_id: u64) -> Option<RewardClaim> {
+        let key = Self::season_key(season_id, owner);
+        self.reward_claims.get(&key).await.ok().flatten()
+    }
+
+    /// Record a paid reward.
+    pub async fn save_reward_claim(&mut self, claim: RewardClaim) {
+        let key = Self::season_key(claim.season_id, &claim.owner);
+        self.reward_claims.insert(key, claim);
+    }
+
+    /// Get all rewards claimed for a season.
+    pub async fn get_season_reward_claims(&self, season_id: u64) -> Vec<RewardClaim> {
+        let mut claims = Vec::new();
+        let _ = self
+            .reward_claims
+            .for_each_key_value_while(
+                |_, claim| {
+                    claims.push(claim.into_owned());
+                    Ok::<_, ViewError>(true)
+                },
+                season_id.to_be_bytes().to_vec(),
+            )
+            .await;
+        claims.sort_by_key(|claim| claim.rank);
+        claims
+    }
+
     // ==================== Quests ====================
 
     /// Get a quest by ID.
@@ -151,12 +965,45 @@ impl ArcadeNexusState {
         self.quests.get(&quest_id).await.ok().flatten()
     }
 
-    /// Save a quest.
+    /// Save a quest and keep the requirement metric index in sync.
     pub async fn save_quest(&mut self, quest: Quest) {
         let id = quest.id;
+        if let Some(previous) = self.quests.get(&id).await.ok().flatten() {
+            if let Some(requirement) = previous.requirement {
+                self.quests_by_metric.remove(Self::metric_quest_key(previous.season_id, requirement.metric, id));
+            }
+        }
+        if let Some(requirement) = quest.requirement.filter(|_| !quest.retired) {
+            self.quests_by_metric.insert(Self::metric_quest_key(quest.season_id, requirement.metric, id), ());
+        }
         let _ = self.quests.insert(&id, quest);
     }
 
+    /// Get the unretired quests of a season whose requirement is on `metric`.
+    pub async fn get_metric_quests(&self, season_id: u64, metric: RequirementMetric) -> Vec<Quest> {
+        let mut ids = Vec::new();
+        let _ = self
+            .quests_by_metric
+            .for_each_key_while(
+                |suffix| {
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(&suffix[..8]);
+                    ids.push(u64::from_be_bytes(bytes));
+                    Ok::<_, ViewError>(true)
+                },
+                Self::metric_quest_prefix(season_id, metric),
+            )
+            .await;
+
+        let mut quests = Vec::new();
+        for id in ids {
+            if let Some(quest) = self.get_quest(id).await {
+                quests.push(quest);
+            }
+        }
+        quests
+    }
+
     /// Get all quests for a season.
     pub async fn get_season_quests(&self, season_id: u64) -> Vec<Quest> {
         let mut quests = Vec::new();
@@ -175,6 +1022,38 @@ impl ArcadeNexusState {
         quests
     }
 
+    // ==================== Quest Templates ====================
+
+    /// Get the next quest template ID and increment the counter.
+    pub async fn get_next_template_id(&mut self) -> u64 {
+        let id = *self.next_template_id.get();
+        self.next_template_id.set(id + 1);
+        id
+    }
+
+    /// Get a quest template by ID.
+    pub async fn get_quest_template(&self, template_id: u64) -> Option<QuestTemplate> {
+        self.quest_templates.get(&template_id).await.ok().flatten()
+    }
+
+    /// Save a quest template.
+    pub async fn save_quest_template(&mut self, template: QuestTemplate) {
+        let id = template.id;
+        let _ = self.quest_templates.insert(&id, template);
+    }
+
+    /// Get all quest templates, oldest first.
+    pub async fn get_all_quest_templates(&self) -> Vec<QuestTemplate> {
+        let mut templates = Vec::new();
+        let keys: Vec<u64> = self.quest_templates.indices().await.unwrap_or_default();
+        for key in keys {
+            if let Some(template) = self.quest_templates.get(&key).await.ok().flatten() {
+                templates.push(template);
+            }
+        }
+        templates
+    }
+
     // ==================== Quest Progress ====================
 
     /// Get quest progress for a player.
@@ -190,6 +1069,10 @@ impl ArcadeNexusState {
                 owner: owner.to_string(),
                 completed: false,
                 completed_at: None,
+                times_completed: 0,
+                progress: 0,
+                baseline: 0,
+                milestones_reached: 0,
             })
     }
 
@@ -199,24 +1082,128 @@ impl ArcadeNexusState {
         let _ = self.quest_progress.insert(&key, progress);
     }
 
-    /// Get all quest progress for a player in a season.
-    pub async fn get_player_quests(&self, owner: &str, season_id: u64) -> Vec<QuestProgress> {
-        let mut progress_list = Vec::new();
-        let keys: Vec<String> = self.quest_progress.indices().await.unwrap_or_default();
-        
-        for key in keys {
-            if key.starts_with(&format!("{}:", owner)) {
-                if let Some(progress) = self.quest_progress.get(&key).await.ok().flatten() {
-                    // Check if quest belongs to this season
-                    if let Some(quest) = self.get_quest(progress.quest_id).await {
-                        if quest.season_id == season_id {
-                            progress_list.push(progress);
-                        }
+    // ==================== Activity Log ====================
+
+    /// Append an entry to the activity log, indexing it under its player and
+    /// adding its XP to the season's daily total.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn log_activity(
+        &mut self,
+        kind: ActivityKind,
+        season_id: u64,
+        player: Option<&str>,
+        xp: u64,
+        quest_id: Option<u64>,
+        game: Option<ApplicationId>,
+        timestamp: i64,
+    ) {
+        let id = self.activity_log.count() as u64;
+        self.activity_log.push(ActivityEntry {
+            id,
+            kind,
+            season_id,
+            player: player.map(str::to_string),
+            xp,
+            quest_id,
+            game,
+            timestamp,
+        });
+
+        if let Some(player) = player {
+            self.player_timelines.insert(Self::timeline_key(player, id), ());
+        }
+
+        if xp > 0 {
+            let day = (timestamp / 86_400) as u64;
+            let mut bucket = self.get_xp_bucket(season_id, day).await;
+            bucket.xp += xp;
+            bucket.grants += 1;
+            let _ = self.xp_buckets.insert(&format!("{}:{}", season_id, day), bucket);
+        }
+    }
+
+    /// Get up to `limit` activity entries, newest first, starting before the
+    /// entry `before` (or at the end of the log).
+    pub async fn get_recent_activity(&self, before: Option<u64>, limit: usize) -> Vec<ActivityEntry> {
+        let count = self.activity_log.count();
+        let end = before.map_or(count, |before| (before as usize).min(count));
+        let start = end.saturating_sub(limit);
+        let mut entries = self.activity_log.read(start..end).await.unwrap_or_default();
+        entries.reverse();
+        entries
+    }
+
+    /// Get up to `limit` of a player's activity entries, newest first,
+    /// starting before the entry `before`, optionally only for one season.
+    pub async fn get_player_timeline(
+        &self,
+        owner: &str,
+        season_id: Option<u64>,
+        before: Option<u64>,
+        limit: usize,
+    ) -> Vec<ActivityEntry> {
+        let mut ids = Vec::new();
+        let _ = self
+            .player_timelines
+            .for_each_key_while(
+                |suffix| {
+                    // Without a season filter the first `limit` entries are enough
+                    if season_id.is_none() && ids.len() >= limit {
+                        return Ok(false);
                     }
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(&suffix[..8]);
+                    let id = u64::MAX - u64::from_be_bytes(bytes);
+                    if before.is_none_or(|before| id < before) {
+                        ids.push(id);
+                    }
+                    Ok::<_, ViewError>(true)
+                },
+                Self::owner_prefix(owner),
+            )
+            .await;
+
+        let mut entries = Vec::new();
+        for id in ids {
+            if entries.len() >= limit {
+                break;
+            }
+            if let Some(entry) = self.activity_log.get(id as usize).await.ok().flatten() {
+                if season_id.is_none_or(|season_id| entry.season_id == season_id) {
+                    entries.push(entry);
                 }
             }
         }
-        
-        progress_list
+        entries
+    }
+
+    /// Get the XP granted in a season on one day.
+    pub async fn get_xp_bucket(&self, season_id: u64, day: u64) -> XpBucket {
+        self.xp_buckets
+            .get(&format!("{}:{}", season_id, day))
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(XpBucket {
+                season_id,
+                day,
+                ..Default::default()
+            })
+    }
+
+    /// Get the daily XP of a season between two days (inclusive), one bucket
+    /// per day including days without XP, stopping after `limit` days.
+    pub async fn get_xp_timeline(
+        &self,
+        season_id: u64,
+        first_day: u64,
+        last_day: u64,
+        limit: usize,
+    ) -> Vec<XpBucket> {
+        let mut buckets = Vec::new();
+        for day in (first_day..=last_day).take(limit) {
+            buckets.push(self.get_xp_bucket(season_id, day).await);
+        }
+        buckets
     }
 }
@@ -0,0 +1,89 @@
+// Copyright (c) Linera Arcade Hub
+// SPDX-License-Identifier: Apache-2.0
+
+/*!
+ * Reporting of player results from the arcade games
+ *
+ * Games credit season XP and attest game events to Arcade Nexus, and add
+ * lifetime stats and meme avatars to Player Profile, for the signer of the
+ * current block.
+ * Both applications are optional and fixed in the game's parameters.
+ */
+
+use arcade_nexus::{ArcadeNexusAbi, GameEventKind};
+use linera_sdk::{linera_base_types::ApplicationId, Contract, ContractRuntime};
+use player_profile::PlayerProfileAbi;
+use serde::{Deserialize, Serialize};
+
+/// Applications a game reports player results to, fixed at creation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReportingParameters {
+    /// Arcade Nexus application credited with season XP
+    #[serde(default)]
+    pub arcade_nexus: Option<ApplicationId<ArcadeNexusAbi>>,
+    /// Player Profile application credited with lifetime stats
+    #[serde(default)]
+    pub player_profile: Option<ApplicationId<PlayerProfileAbi>>,
+}
+
+/// Credit season XP to the signer in Arcade Nexus, if the game reports there.
+pub fn report_xp<C>(runtime: &mut ContractRuntime<C>, points: u64)
+where
+    C: Contract<Parameters = ReportingParameters>,
+{
+    report_to_nexus(runtime, arcade_nexus::Operation::RecordGameAction { season_id: None, points });
+}
+
+/// Attest a game event of the signer to Arcade Nexus for quest requirements.
+pub fn report_event<C>(runtime: &mut ContractRuntime<C>, kind: GameEventKind, value: u64)
+where
+    C: Contract<Parameters = ReportingParameters>,
+{
+    report_to_nexus(runtime, arcade_nexus::Operation::RecordGameEvent { season_id: None, kind, value });
+}
+
+fn report_to_nexus<C>(runtime: &mut ContractRuntime<C>, operation: arcade_nexus::Operation)
+where
+    C: Contract<Parameters = ReportingParameters>,
+{
+    let nexus = match runtime.application_parameters().arcade_nexus {
+        Some(nexus) => nexus,
+        None => return,
+    };
+    // A rejected report must not undo the game action
+    let _ = runtime.call_application(true, nexus, &operation);
+}
+
+/// Add XP, games played and wins to the signer's Player Profile, if the game reports there.
+pub fn report_stats<C>(runtime: &mut ContractRuntime<C>, xp_delta: u64, games_delta: u64, wins_delta: u64)
+where
+    C: Contract<Parameters = ReportingParameters>,
+{
+    let profiles = match runtime.application_parameters().player_profile {
+        Some(profiles) => profiles,
+        None => return,
+    };
+    // A rejected report must not undo the game action
+    let _ = runtime.call_application(
+        true,
+        profiles,
+        &player_profile::Operation::UpdateStats { xp_delta, games_delta, wins_delta },
+    );
+}
+
+/// Make a meme the game has checked the signer owns their Player Profile avatar.
+pub fn report_meme_avatar<C>(runtime: &mut ContractRuntime<C>, meme_id: u64, image_url: String)
+where
+    C: Contract<Parameters = ReportingParameters>,
+{
+    let profiles = match runtime.application_parameters().player_profile {
+        Some(profiles) => profiles,
+        None => return,
+    };
+    // A rejected avatar must not undo the game action
+    let _ = runtime.call_application(
+        true,
+        profiles,
+        &player_profile::Operation::SetMemeAvatar { meme_id, image_url },
+    );
+}
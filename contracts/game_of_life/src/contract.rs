@@ -8,14 +8,16 @@
 mod state;
 
 use linera_sdk::{
-    linera_base_types::WithContractAbi,
+    linera_base_types::{AccountOwner, WithContractAbi},
     views::{RootView, View},
     Contract, ContractRuntime,
 };
 use game_of_life::{
-    GameOfLifeAbi, GridState, Operation, OperationResult, 
-    Pattern, GRID_WIDTH, GRID_HEIGHT,
+    GameOfLifeAbi, GameOfLifeParameters, GridState, Operation, OperationResult, 
+    Pattern, PlayerProgress, GRID_WIDTH, GRID_HEIGHT, PATTERN_XP,
 };
+use arcade_reporting::{report_event, report_stats, report_xp};
+use arcade_nexus::GameEventKind;
 
 use self::state::GameOfLifeState;
 
@@ -34,7 +36,7 @@ impl WithContractAbi for GameOfLifeContract {
 impl Contract for GameOfLifeContract {
     type Message = ();
     type InstantiationArgument = ();
-    type Parameters = ();
+    type Parameters = GameOfLifeParameters;
     type EventValue = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -82,6 +84,7 @@ impl Contract for GameOfLifeContract {
                 let mut grid = self.state.grid.get().clone();
                 grid.step();
                 self.state.grid.set(grid.clone());
+                self.report_generation(grid.generation).await;
                 OperationResult::Ok {
                     generation: grid.generation,
                     live_count: grid.live_count(),
@@ -94,6 +97,7 @@ impl Contract for GameOfLifeContract {
                     grid.step();
                 }
                 self.state.grid.set(grid.clone());
+                self.report_generation(grid.generation).await;
                 OperationResult::Ok {
                     generation: grid.generation,
                     live_count: grid.live_count(),
@@ -173,6 +177,7 @@ impl Contract for GameOfLifeContract {
                 }
                 
                 self.state.grid.set(grid.clone());
+                self.reward_pattern().await;
                 OperationResult::Ok {
                     generation: grid.generation,
                     live_count: grid.live_count(),
@@ -189,3 +194,42 @@ impl Contract for GameOfLifeContract {
         self.state.save().await.expect("Failed to save state");
     }
 }
+
+impl GameOfLifeContract {
+    /// Progress of the signer of the current block, if there is one
+    async fn signer_progress(&mut self) -> Option<(AccountOwner, PlayerProgress)> {
+        let signer = self.runtime.authenticated_signer()?;
+        let progress = self.state.players.get(&signer).await
+            .expect("Failed to read player progress")
+            .unwrap_or_default();
+        Some((signer, progress))
+    }
+
+    /// Credit pattern XP to the signer, up to the daily cap
+    async fn reward_pattern(&mut self) {
+        let Some((signer, mut progress)) = self.signer_progress().await else {
+            return;
+        };
+        let day = self.runtime.system_time().micros() / 86_400_000_000;
+        if !progress.reward_pattern(day) {
+            return;
+        }
+        self.state.players.insert(&signer, progress)
+            .expect("Failed to store player progress");
+        report_xp(&mut self.runtime, PATTERN_XP);
+        report_stats(&mut self.runtime, PATTERN_XP, 1, 0);
+    }
+
+    /// Report the generation to Arcade Nexus when the signer passes a new milestone
+    async fn report_generation(&mut self, generation: u64) {
+        let Some((signer, mut progress)) = self.signer_progress().await else {
+            return;
+        };
+        let Some(milestone) = progress.reach_generation(generation) else {
+            return;
+        };
+        self.state.players.insert(&signer, progress)
+            .expect("Failed to store player progress");
+        report_event(&mut self.runtime, GameEventKind::LifeGenerationReached, milestone);
+    }
+}
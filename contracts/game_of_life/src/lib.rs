@@ -36,6 +36,54 @@ impl ServiceAbi for GameOfLifeAbi {
     type QueryResponse = Response;
 }
 
+/// Applications this game reports player results to, fixed at creation
+pub type GameOfLifeParameters = arcade_reporting::ReportingParameters;
+
+/// Season XP for loading a pattern
+pub const PATTERN_XP: u64 = 10;
+
+/// Patterns a signer is credited XP for per UTC day
+pub const MAX_REWARDED_PATTERNS_PER_DAY: u32 = 5;
+
+/// Generations are reported to Arcade Nexus in steps of this size
+pub const GENERATION_REPORT_INTERVAL: u64 = 10;
+
+/// What a signer has been credited for so far
+#[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct PlayerProgress {
+    /// Highest generation milestone reported for this signer
+    pub best_generation: u64,
+    /// UTC day (seconds since epoch / 86400) the pattern counter refers to
+    pub pattern_day: u64,
+    /// Patterns credited XP on `pattern_day`
+    pub rewarded_patterns: u32,
+}
+
+impl PlayerProgress {
+    /// Count a loaded pattern and tell whether it still earns XP today
+    pub fn reward_pattern(&mut self, day: u64) -> bool {
+        if self.pattern_day != day {
+            self.pattern_day = day;
+            self.rewarded_patterns = 0;
+        }
+        if self.rewarded_patterns >= MAX_REWARDED_PATTERNS_PER_DAY {
+            return false;
+        }
+        self.rewarded_patterns += 1;
+        true
+    }
+
+    /// Milestone to report for a generation, if it beats the best reported one
+    pub fn reach_generation(&mut self, generation: u64) -> Option<u64> {
+        let milestone = generation / GENERATION_REPORT_INTERVAL * GENERATION_REPORT_INTERVAL;
+        if milestone <= self.best_generation {
+            return None;
+        }
+        self.best_generation = milestone;
+        Some(milestone)
+    }
+}
+
 /// The state of the grid
 #[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
 pub struct GridState {
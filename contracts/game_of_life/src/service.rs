@@ -16,7 +16,7 @@ use linera_sdk::{
     views::View,
     Service, ServiceRuntime,
 };
-use game_of_life::{GameOfLifeAbi, Operation, GRID_WIDTH, GRID_HEIGHT};
+use game_of_life::{GameOfLifeAbi, GameOfLifeParameters, Operation, GRID_WIDTH, GRID_HEIGHT};
 
 use self::state::GameOfLifeState;
 
@@ -34,7 +34,7 @@ impl WithServiceAbi for GameOfLifeService {
 }
 
 impl Service for GameOfLifeService {
-    type Parameters = ();
+    type Parameters = GameOfLifeParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = GameOfLifeState::load(runtime.root_view_storage_context())
@@ -3,8 +3,11 @@
 
 //! State definitions for the Game of Life application
 
-use linera_sdk::views::{linera_views, RegisterView, RootView, ViewStorageContext};
-use game_of_life::GridState;
+use linera_sdk::{
+    linera_base_types::AccountOwner,
+    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
+};
+use game_of_life::{GridState, PlayerProgress};
 
 /// The application state stored on-chain
 #[derive(RootView, async_graphql::SimpleObject)]
@@ -12,4 +15,6 @@ use game_of_life::GridState;
 pub struct GameOfLifeState {
     /// The current grid state
     pub grid: RegisterView<GridState>,
+    /// Rewards and reports credited to each signer
+    pub players: MapView<AccountOwner, PlayerProgress>,
 }
@@ -9,7 +9,8 @@ use linera_sdk::{
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use meme_auction::{Auction, AuctionStatus, Bid, MemeRarity, Operation, MemeAuctionAbi};
+use meme_auction::{Auction, AuctionStatus, Bid, MemeRarity, Operation, MemeAuctionAbi, MemeAuctionParameters, BID_XP, CREATE_XP, WIN_XP};
+use arcade_reporting::{report_meme_avatar, report_stats, report_xp};
 use state::MemeAuctionState;
 
 /// The MemeAuction contract.
@@ -27,7 +28,7 @@ impl WithContractAbi for MemeAuctionContract {
 impl Contract for MemeAuctionContract {
     type Message = ();
     type InstantiationArgument = ();
-    type Parameters = ();
+    type Parameters = MemeAuctionParameters;
     type EventValue = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -88,6 +89,9 @@ impl Contract for MemeAuctionContract {
                 let mut stats = self.state.get_player_stats(&owner).await;
                 stats.auctions_created += 1;
                 self.state.save_player_stats(&owner, stats).await;
+
+                report_xp(&mut self.runtime, CREATE_XP);
+                report_stats(&mut self.runtime, CREATE_XP, 0, 0);
             }
 
             Operation::PlaceBid { auction_id, amount } => {
@@ -138,6 +142,9 @@ impl Contract for MemeAuctionContract {
                     stats.total_bids += 1;
                     stats.total_spent = stats.total_spent.saturating_add(amount);
                     self.state.save_player_stats(&owner, stats).await;
+
+                    report_xp(&mut self.runtime, BID_XP);
+                    report_stats(&mut self.runtime, BID_XP, 0, 0);
                 }
             }
 
@@ -211,6 +218,20 @@ impl Contract for MemeAuctionContract {
                     let mut stats = self.state.get_player_stats(&owner).await;
                     stats.memes_collected += 1;
                     self.state.save_player_stats(&owner, stats).await;
+
+                    report_xp(&mut self.runtime, WIN_XP);
+                    report_stats(&mut self.runtime, WIN_XP, 0, 1);
+                }
+            }
+
+            Operation::UseMemeAsAvatar { auction_id } => {
+                if let Some(auction) = self.state.get_auction(auction_id).await {
+                    // Must own the meme
+                    if self.state.get_meme_owner(auction.meme_id).await.as_ref() != Some(&owner) {
+                        return; // Not the owner
+                    }
+
+                    report_meme_avatar(&mut self.runtime, auction.meme_id, auction.image_url);
                 }
             }
         }
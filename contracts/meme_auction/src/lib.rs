@@ -21,6 +21,18 @@ impl linera_sdk::linera_base_types::ServiceAbi for MemeAuctionAbi {
     type QueryResponse = async_graphql::Response;
 }
 
+/// Applications this game reports player results to, fixed at creation.
+pub type MemeAuctionParameters = arcade_reporting::ReportingParameters;
+
+/// Season XP for creating an auction.
+pub const CREATE_XP: u64 = 25;
+
+/// Season XP for placing a bid.
+pub const BID_XP: u64 = 10;
+
+/// Season XP for claiming a won meme.
+pub const WIN_XP: u64 = 75;
+
 /// Status of an auction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
 pub enum AuctionStatus {
@@ -136,4 +148,8 @@ pub enum Operation {
     ClaimMeme {
         auction_id: u64,
     },
+    /// Use the meme of an auction as Player Profile avatar (meme owner only).
+    UseMemeAsAvatar {
+        auction_id: u64,
+    },
 }
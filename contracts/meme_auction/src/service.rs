@@ -12,7 +12,7 @@ use linera_sdk::{
     views::{RootView, View},
     Service, ServiceRuntime,
 };
-use meme_auction::{Auction, AuctioneerStats, AuctionStatus, Bid, MemeAuctionAbi, Operation};
+use meme_auction::{Auction, AuctioneerStats, AuctionStatus, Bid, MemeAuctionAbi, MemeAuctionParameters, Operation};
 use state::MemeAuctionState;
 
 /// The MemeAuction service.
@@ -28,7 +28,7 @@ impl WithServiceAbi for MemeAuctionService {
 }
 
 impl Service for MemeAuctionService {
-    type Parameters = ();
+    type Parameters = MemeAuctionParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = MemeAuctionState::load(runtime.root_view_storage_context())
@@ -9,7 +9,9 @@ use linera_sdk::{
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use meme_battle::{Match, MatchStatus, MemeRef, Operation, MemeBattleAbi, Round, Tournament, TournamentStatus};
+use meme_battle::{Match, MatchStatus, MemeRef, Operation, MemeBattleAbi, MemeBattleParameters, Round, Tournament, TournamentStatus, VOTE_XP};
+use arcade_reporting::{report_event, report_stats, report_xp};
+use arcade_nexus::GameEventKind;
 use state::MemeBattleState;
 
 /// The Meme Battle contract.
@@ -27,7 +29,7 @@ impl WithContractAbi for MemeBattleContract {
 impl Contract for MemeBattleContract {
     type Message = ();
     type InstantiationArgument = ();
-    type Parameters = ();
+    type Parameters = MemeBattleParameters;
     type EventValue = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -152,8 +154,9 @@ impl Contract for MemeBattleContract {
                     self.state.save_vote(match_id, &owner, choice).await;
                     self.state.save_tournament(tournament).await;
 
-                    // TODO: Send XP to Arcade Nexus (+15 XP per vote)
-                    // This requires cross-application messaging which we'll add in integration phase
+                    report_xp(&mut self.runtime, VOTE_XP);
+                    report_stats(&mut self.runtime, VOTE_XP, 1, 0);
+                    report_event(&mut self.runtime, GameEventKind::MemeBattleVote, 1);
                 }
             }
 
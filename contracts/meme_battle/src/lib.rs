@@ -25,6 +25,12 @@ impl linera_sdk::linera_base_types::ServiceAbi for MemeBattleAbi {
     type QueryResponse = async_graphql::Response;
 }
 
+/// Applications this game reports player results to, fixed at creation.
+pub type MemeBattleParameters = arcade_reporting::ReportingParameters;
+
+/// Season XP for voting in a match.
+pub const VOTE_XP: u64 = 10;
+
 /// Tournament status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
 pub enum TournamentStatus {
@@ -12,7 +12,7 @@ use linera_sdk::{
     views::{RootView, View},
     Service, ServiceRuntime,
 };
-use meme_battle::{MemeBattleAbi, Operation, Tournament};
+use meme_battle::{MemeBattleAbi, MemeBattleParameters, Operation, Tournament};
 use state::MemeBattleState;
 
 /// The Meme Battle service.
@@ -28,7 +28,7 @@ impl WithServiceAbi for MemeBattleService {
 }
 
 impl Service for MemeBattleService {
-    type Parameters = ();
+    type Parameters = MemeBattleParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = MemeBattleState::load(runtime.root_view_storage_context())
@@ -7,20 +7,44 @@
 //! 
 //! This contract handles:
 //! - Profile registration
-//! - Stats updates (from games)
-//! - Name changes
+//! - Stats updates (from registered game applications only)
+//! - Level-up events when XP crosses a level threshold
+//! - Name changes, with unique names, reservations and cooldowns
+//! - Profile details (avatar, bio, region, links, privacy)
+//! - Follows, friend requests and blocks
+//! - Linking further account owners and chains to a profile
+//! - Profile deletion and two-step transfer to a new owner
+//! - Badge catalog, badge awards from the games each badge allows, revocations and showcases
+//!
+//! The caller is identified by the authenticated signer, resolved through
+//! identity links to the canonical profile owner. Every profile lives on the
+//! application's creator chain; operations on other chains are forwarded there.
 
 mod state;
 
 use linera_sdk::{
-    linera_base_types::WithContractAbi,
+    linera_base_types::{AccountOwner, ApplicationId, ChainId, StreamName, WithContractAbi},
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use player_profile::{Operation, OperationResult, PlayerProfile, PlayerProfileAbi, ProfileError};
+use player_profile::{AwardedBadge, Avatar, Badge, GameStats, MemeAvatar, Message, NameChange, Operation, OperationResult, PlayerProfile, PlayerProfileAbi, ProfileError, ProfileEvent, ProfileParameters, RegisteredGame, Relation, SocialLink, LEVEL_UP_STREAM, MAX_BIO_LENGTH, MAX_RELATIONSHIPS, MAX_SHOWCASE_BADGES, MAX_SOCIAL_LINKS};
 
 use self::state::PlayerProfileState;
 
+/// A change to the relationship between two players
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SocialAction {
+    Follow,
+    Unfollow,
+    SendFriendRequest,
+    CancelFriendRequest,
+    AcceptFriendRequest,
+    DeclineFriendRequest,
+    RemoveFriend,
+    Block,
+    Unblock,
+}
+
 /// The contract handler
 pub struct PlayerProfileContract {
     state: PlayerProfileState,
@@ -34,10 +58,10 @@ impl WithContractAbi for PlayerProfileContract {
 }
 
 impl Contract for PlayerProfileContract {
-    type Message = ();
+    type Message = Message;
     type InstantiationArgument = ();
-    type Parameters = ();
-    type EventValue = ();
+    type Parameters = ProfileParameters;
+    type EventValue = ProfileEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = PlayerProfileState::load(runtime.root_view_storage_context())
@@ -47,12 +71,148 @@ impl Contract for PlayerProfileContract {
     }
 
     async fn instantiate(&mut self, _argument: Self::InstantiationArgument) {
-        // No initialization needed
+        // Reject a level curve that would make levels ambiguous
+        let parameters = self.runtime.application_parameters();
+        assert!(parameters.level_curve.is_valid(), "Level thresholds must be strictly ascending");
+        
+        // The creator manages reserved names and the game registry
+        let admin = self.get_owner_address().await
+            .expect("Application must be created by an account owner");
+        self.state.admin.set(admin);
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> OperationResult {
-        // Get the owner (caller) address
-        let owner = self.get_owner_address();
+        // Block checks need no signer, but only the creator chain holds the
+        // social graph
+        if let Operation::CheckBlocked { player_a, player_b } = &operation {
+            if self.runtime.chain_id() != self.runtime.application_creator_chain_id() {
+                return OperationResult::Error(ProfileError::NotCreatorChain.to_string());
+            }
+            let player_a = self.state.resolve_owner(player_a).await;
+            let player_b = self.state.resolve_owner(player_b).await;
+            return match self.state.is_blocked_between(&player_a, &player_b).await {
+                Ok(blocked) => OperationResult::BlockStatus { blocked },
+                Err(e) => OperationResult::Error(ProfileError::StateError(e.to_string()).to_string()),
+            };
+        }
+        
+        let signer = match self.runtime.authenticated_signer() {
+            Some(signer) => signer,
+            None => return OperationResult::Error(ProfileError::Unauthenticated.to_string()),
+        };
+        
+        let message = match operation {
+            Operation::UpdateStats { xp_delta, games_delta, wins_delta } => {
+                // Only game applications may report stats, never users directly
+                let game = match self.runtime.authenticated_caller_id() {
+                    Some(game) => game,
+                    None => return OperationResult::Error(ProfileError::Unauthorized.to_string()),
+                };
+                Message::UpdateStats { game, xp_delta, games_delta, wins_delta }
+            }
+            Operation::AwardBadge { badge_id } => {
+                // Badges are awarded by games, never claimed by users directly
+                let game = match self.runtime.authenticated_caller_id() {
+                    Some(game) => game,
+                    None => return OperationResult::Error(ProfileError::Unauthorized.to_string()),
+                };
+                Message::AwardBadge { game, badge_id }
+            }
+            Operation::SetMemeAvatar { meme_id, image_url } => {
+                // Meme ownership is checked by the game holding the meme
+                let game = match self.runtime.authenticated_caller_id() {
+                    Some(game) => game,
+                    None => return OperationResult::Error(ProfileError::Unauthorized.to_string()),
+                };
+                Message::SetMemeAvatar { game, meme_id, image_url }
+            }
+            Operation::MigrateChainProfile => {
+                // Profiles used to be stored on each player's own chain
+                let chain_id = self.runtime.chain_id().to_string();
+                let profile = self.state.profiles.get(&chain_id).await.ok().flatten();
+                Message::MigrateProfile { profile }
+            }
+            operation => Message::Operation(operation),
+        };
+        
+        // Profiles, names and registries live on the creator chain
+        let creator_chain = self.runtime.application_creator_chain_id();
+        if self.runtime.chain_id() == creator_chain {
+            return self.handle_message(signer, creator_chain, message).await;
+        }
+        self.runtime
+            .prepare_message(message)
+            .with_authentication()
+            .send_to(creator_chain);
+        OperationResult::OperationForwarded
+    }
+
+    async fn execute_message(&mut self, message: Self::Message) {
+        // Bounced messages carry nothing to undo
+        if self.runtime.message_is_bouncing() == Some(true) {
+            return;
+        }
+        
+        // Messages are only sent with authentication
+        let signer = match self.runtime.authenticated_signer() {
+            Some(signer) => signer,
+            None => return,
+        };
+        let origin = self.runtime.message_origin_chain_id()
+            .expect("Incoming message must have an origin chain");
+        
+        // Rejected messages have no sender to answer to
+        let _ = self.handle_message(signer, origin, message).await;
+    }
+
+    async fn store(mut self) {
+        self.state.save().await.expect("Failed to save state");
+    }
+}
+
+impl PlayerProfileContract {
+    /// Apply a request of `signer`, submitted on the chain `origin`, on the creator chain
+    async fn handle_message(&mut self, signer: AccountOwner, origin: ChainId, message: Message) -> OperationResult {
+        match message {
+            Message::Operation(operation) => self.apply_operation(signer, origin, operation).await,
+            Message::UpdateStats { game, xp_delta, games_delta, wins_delta } => {
+                let owner = self.state.resolve_owner(&signer.to_string()).await;
+                match self.update_stats(&owner, game, xp_delta, games_delta, wins_delta).await {
+                    Ok(profile) => OperationResult::StatsUpdated {
+                        new_xp: profile.xp,
+                        new_games: profile.games_played,
+                        new_wins: profile.wins,
+                        new_level: self.runtime.application_parameters().level_curve.level_for(profile.xp),
+                    },
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Message::AwardBadge { game, badge_id } => {
+                let owner = self.state.resolve_owner(&signer.to_string()).await;
+                match self.award_badge(&owner, game, &badge_id).await {
+                    Ok(()) => OperationResult::BadgeAwarded { badge_id },
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Message::SetMemeAvatar { game, meme_id, image_url } => {
+                let owner = self.state.resolve_owner(&signer.to_string()).await;
+                match self.set_meme_avatar(&owner, game, meme_id, image_url).await {
+                    Ok(()) => OperationResult::ProfileUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Message::MigrateProfile { profile } => {
+                match self.migrate_chain_profile(signer, origin, profile).await {
+                    Ok(()) => OperationResult::ProfileTransferred { new_owner: signer.to_string() },
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+        }
+    }
+    
+    /// Apply an operation of `signer`, submitted on the chain `origin`, on the creator chain
+    async fn apply_operation(&mut self, signer: AccountOwner, origin: ChainId, operation: Operation) -> OperationResult {
+        let owner = self.state.resolve_owner(&signer.to_string()).await;
         
         match operation {
             Operation::Register { name } => {
@@ -61,40 +221,315 @@ impl Contract for PlayerProfileContract {
                     Err(e) => OperationResult::Error(e.to_string()),
                 }
             }
-            Operation::UpdateStats { xp_delta, games_delta, wins_delta } => {
-                match self.update_stats(&owner, xp_delta, games_delta, wins_delta).await {
+            Operation::UpdateStats { .. } => {
+                // Sent as its own message with the reporting game, see `execute_operation`
+                OperationResult::Error(ProfileError::Unauthorized.to_string())
+            }
+            Operation::UpdateName { name } => {
+                match self.update_name(&owner, name).await {
+                    Ok(_) => OperationResult::ProfileUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::UpdateDetails { avatar, bio, region, links, private } => {
+                match self.update_details(&owner, avatar, bio, region, links, private).await {
+                    Ok(_) => OperationResult::ProfileUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::SetMemeAvatar { .. } => {
+                // Sent as its own message with the calling game, see `execute_operation`
+                OperationResult::Error(ProfileError::Unauthorized.to_string())
+            }
+            Operation::Follow { player } => {
+                self.relationship_result(&owner, &player, SocialAction::Follow).await
+            }
+            Operation::Unfollow { player } => {
+                self.relationship_result(&owner, &player, SocialAction::Unfollow).await
+            }
+            Operation::SendFriendRequest { player } => {
+                self.relationship_result(&owner, &player, SocialAction::SendFriendRequest).await
+            }
+            Operation::CancelFriendRequest { player } => {
+                self.relationship_result(&owner, &player, SocialAction::CancelFriendRequest).await
+            }
+            Operation::AcceptFriendRequest { player } => {
+                self.relationship_result(&owner, &player, SocialAction::AcceptFriendRequest).await
+            }
+            Operation::DeclineFriendRequest { player } => {
+                self.relationship_result(&owner, &player, SocialAction::DeclineFriendRequest).await
+            }
+            Operation::RemoveFriend { player } => {
+                self.relationship_result(&owner, &player, SocialAction::RemoveFriend).await
+            }
+            Operation::Block { player } => {
+                self.relationship_result(&owner, &player, SocialAction::Block).await
+            }
+            Operation::Unblock { player } => {
+                self.relationship_result(&owner, &player, SocialAction::Unblock).await
+            }
+            Operation::CheckBlocked { .. } => unreachable!("Handled before authentication"),
+            Operation::RequestLink { identity } => {
+                match self.request_link(&owner, identity).await {
+                    Ok(()) => OperationResult::LinksUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::ConfirmLink { profile_owner } => {
+                match self.confirm_link(&profile_owner, signer, origin).await {
+                    Ok(()) => OperationResult::LinksUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::MigrateChainProfile => {
+                // Sent as its own message with the chain's profile, see `execute_operation`
+                OperationResult::Error(ProfileError::ProfileNotFound.to_string())
+            }
+            Operation::Unlink { identity } => {
+                match self.unlink(&owner, &identity).await {
+                    Ok(()) => OperationResult::LinksUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::DeleteProfile => {
+                match self.delete_profile(&owner).await {
+                    Ok(()) => OperationResult::ProfileDeleted,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::TransferProfile { new_owner } => {
+                match self.request_transfer(&owner, new_owner).await {
+                    Ok(()) => OperationResult::ProfileUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::CancelProfileTransfer => {
+                match self.cancel_transfer(&owner).await {
+                    Ok(()) => OperationResult::ProfileUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::AcceptProfileTransfer { from } => {
+                // The new owner is the signer itself, even if it is linked to a profile
+                let new_owner = signer.to_string();
+                match self.accept_transfer(&from, &new_owner).await {
+                    Ok(()) => OperationResult::ProfileTransferred { new_owner },
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::ReserveName { name } => {
+                match self.set_name_reserved(&owner, &name, true).await {
+                    Ok(()) => OperationResult::ReservedNamesUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::ReleaseName { name } => {
+                match self.set_name_reserved(&owner, &name, false).await {
+                    Ok(()) => OperationResult::ReservedNamesUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::TransferAdmin { new_admin } => {
+                match self.transfer_admin(&owner, &new_admin).await {
+                    Ok(new_admin) => OperationResult::AdminTransferred { new_admin },
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::RegisterGame { application_id, name } => {
+                match self.register_game(&owner, application_id, name).await {
+                    Ok(()) => OperationResult::GamesUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::UnregisterGame { application_id } => {
+                match self.unregister_game(&owner, application_id).await {
+                    Ok(()) => OperationResult::GamesUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::SetGameStats { owner: player, game, xp, games_played, wins } => {
+                match self.set_game_stats(&owner, &player, game, xp, games_played, wins).await {
                     Ok(profile) => OperationResult::StatsUpdated {
                         new_xp: profile.xp,
                         new_games: profile.games_played,
                         new_wins: profile.wins,
+                        new_level: self.runtime.application_parameters().level_curve.level_for(profile.xp),
                     },
                     Err(e) => OperationResult::Error(e.to_string()),
                 }
             }
-            Operation::UpdateName { name } => {
-                match self.update_name(&owner, name).await {
-                    Ok(_) => OperationResult::ProfileUpdated,
+            Operation::BackfillRankings { after, limit } => {
+                match self.backfill_rankings(&owner, after, limit).await {
+                    Ok(next) => OperationResult::RankingsBackfilled { next },
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::DefineBadge { id, title, icon_url, rarity, criteria, games } => {
+                let badge = Badge { id, title, icon_url, rarity, criteria, games, created_at: 0, holders: 0 };
+                match self.define_badge(&owner, badge).await {
+                    Ok(()) => OperationResult::BadgesUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::AwardBadge { .. } => {
+                // Sent as its own message with the awarding game, see `execute_operation`
+                OperationResult::Error(ProfileError::Unauthorized.to_string())
+            }
+            Operation::RevokeBadge { owner: player, badge_id } => {
+                match self.revoke_badge(&owner, &player, &badge_id).await {
+                    Ok(()) => OperationResult::BadgesUpdated,
+                    Err(e) => OperationResult::Error(e.to_string()),
+                }
+            }
+            Operation::SetShowcase { badge_ids } => {
+                match self.set_showcase(&owner, badge_ids).await {
+                    Ok(()) => OperationResult::BadgesUpdated,
                     Err(e) => OperationResult::Error(e.to_string()),
                 }
             }
         }
     }
-
-    async fn execute_message(&mut self, _message: Self::Message) {
-        // No cross-chain messages supported yet
-        panic!("PlayerProfile does not support cross-chain messages");
+    
+    /// Get the owner address as a string
+    async fn get_owner_address(&mut self) -> Result<String, ProfileError> {
+        // Use the authenticated signer as the owner identifier
+        let signer = self.runtime.authenticated_signer()
+            .ok_or(ProfileError::Unauthenticated)?;
+        Ok(self.state.resolve_owner(&signer.to_string()).await)
     }
-
-    async fn store(mut self) {
-        self.state.save().await.expect("Failed to save state");
+    
+    /// Ask to link an account owner or chain to the caller's profile
+    async fn request_link(&mut self, owner: &str, identity: String) -> Result<(), ProfileError> {
+        let identity = identity.trim().to_string();
+        if identity == owner {
+            return Err(ProfileError::CannotTargetSelf);
+        }
+        
+        // The caller must have a profile to link to
+        if self.state.profiles.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .is_none()
+        {
+            return Err(ProfileError::ProfileNotFound);
+        }
+        
+        self.check_linkable(&identity).await?;
+        
+        // A pending request is only replaced by the profile that made it
+        let requested_by = self.state.pending_links.get(&identity).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        if requested_by.is_some_and(|requested_by| requested_by != owner) {
+            return Err(ProfileError::LinkRequestedByOther);
+        }
+        self.state.add_link_request(owner, &identity)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
     }
-}
-
-impl PlayerProfileContract {
-    /// Get the owner address as a string
-    fn get_owner_address(&mut self) -> String {
-        // Use the chain ID as the owner identifier
-        format!("{}", self.runtime.chain_id())
+    
+    /// Check that an identity is free to be linked to a profile
+    async fn check_linkable(&self, identity: &str) -> Result<(), ProfileError> {
+        if self.state.identity_links.contains_key(identity).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::AlreadyLinked);
+        }
+        
+        // Linking would hide the identity's own profile
+        if self.state.profiles.contains_key(identity).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::IdentityHasProfile);
+        }
+        
+        Ok(())
+    }
+    
+    /// Confirm a pending link as the signer or the chain it was sent from,
+    /// whichever the profile owner asked to link
+    async fn confirm_link(&mut self, profile_owner: &str, signer: AccountOwner, chain_id: ChainId) -> Result<(), ProfileError> {
+        let candidates = [chain_id.to_string(), signer.to_string()];
+        
+        let mut identity = None;
+        for candidate in candidates {
+            let requested_by = self.state.pending_links.get(&candidate).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            if requested_by.as_deref() == Some(profile_owner) {
+                identity = Some(candidate);
+                break;
+            }
+        }
+        let identity = identity.ok_or(ProfileError::LinkNotRequested)?;
+        
+        self.check_linkable(&identity).await?;
+        self.state.remove_link_request(profile_owner, &identity)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.identity_links.insert(&identity, profile_owner.to_string())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        let mut linked = self.state.get_linked_identities(profile_owner).await;
+        linked.push(identity);
+        self.state.linked_identities.insert(profile_owner, linked)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Move a profile keyed by the chain `origin` to `signer`: the one stored on
+    /// this chain under that key, or else the copy the origin chain sent
+    async fn migrate_chain_profile(
+        &mut self,
+        signer: AccountOwner,
+        origin: ChainId,
+        profile: Option<PlayerProfile>,
+    ) -> Result<(), ProfileError> {
+        let chain_key = origin.to_string();
+        let new_owner = signer.to_string();
+        
+        if self.state.profiles.contains_key(&chain_key).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            // Move it with everything attached, as in a profile transfer
+            self.state.pending_transfers.insert(&chain_key, new_owner.clone())
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            return self.accept_transfer(&chain_key, &new_owner).await;
+        }
+        
+        let mut profile = profile.ok_or(ProfileError::ProfileNotFound)?;
+        // Badges only exist on this chain, so a copy has none to show
+        profile.showcase.clear();
+        if self.state.profiles.contains_key(&new_owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::ProfileExists);
+        }
+        self.check_linkable(&new_owner).await?;
+        self.check_name_available(&new_owner, &profile.name).await?;
+        
+        self.state.profiles.insert(&new_owner, profile.clone())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(&new_owner, None, Some(&profile)).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.name_index.insert(&PlayerProfileState::normalize_name(&profile.name), new_owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Remove a linked identity from the caller's profile
+    async fn unlink(&mut self, owner: &str, identity: &str) -> Result<(), ProfileError> {
+        let mut linked = self.state.get_linked_identities(owner).await;
+        if !linked.iter().any(|i| i == identity) {
+            return Err(ProfileError::NotLinked);
+        }
+        linked.retain(|i| i != identity);
+        
+        self.state.identity_links.remove(identity)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.linked_identities.insert(owner, linked)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
     }
     
     /// Get current timestamp in milliseconds
@@ -123,60 +558,982 @@ impl PlayerProfileContract {
         Ok(())
     }
     
-    /// Register a new profile
-    async fn register_profile(&mut self, owner: &str, name: String) -> Result<PlayerProfile, ProfileError> {
-        // Validate name
-        Self::validate_name(&name)?;
+    /// Validate a URL used for an avatar or social link
+    fn is_valid_url(url: &str, schemes: &[&str]) -> bool {
+        url.len() <= 200
+            && schemes.iter().any(|scheme| url.len() > scheme.len() && url.starts_with(scheme))
+            && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+    }
+    
+    /// Validate an avatar with exactly one source set
+    fn validate_avatar(avatar: &Avatar) -> Result<(), ProfileError> {
+        if avatar.meme.is_some() {
+            return Err(ProfileError::InvalidAvatar(
+                "Meme avatars are set by the game the meme is owned in".to_string()
+            ));
+        }
+        if avatar.url.is_some() == avatar.content_hash.is_some() {
+            return Err(ProfileError::InvalidAvatar(
+                "Set exactly one of url or content hash".to_string()
+            ));
+        }
+        if let Some(url) = &avatar.url {
+            if !Self::is_valid_url(url, &["https://", "ipfs://"]) {
+                return Err(ProfileError::InvalidAvatar(
+                    "URL must start with https:// or ipfs:// and be at most 200 characters".to_string()
+                ));
+            }
+        }
+        if let Some(hash) = &avatar.content_hash {
+            if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ProfileError::InvalidAvatar(
+                    "Content hash must be 64 hex characters".to_string()
+                ));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Validate a profile bio
+    fn validate_bio(bio: &str) -> Result<(), ProfileError> {
+        if bio.chars().count() > MAX_BIO_LENGTH {
+            return Err(ProfileError::InvalidBio(
+                format!("Bio must be at most {} characters", MAX_BIO_LENGTH)
+            ));
+        }
+        if bio.chars().any(|c| c.is_control() && c != '\n') {
+            return Err(ProfileError::InvalidBio(
+                "Bio cannot contain control characters".to_string()
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Validate a region code
+    fn validate_region(region: &str) -> Result<(), ProfileError> {
+        if region.len() != 2 || !region.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(ProfileError::InvalidRegion(
+                "Region must be a two-letter uppercase code".to_string()
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Validate a list of social links
+    fn validate_links(links: &[SocialLink]) -> Result<(), ProfileError> {
+        if links.len() > MAX_SOCIAL_LINKS {
+            return Err(ProfileError::InvalidLink(
+                format!("At most {} links are allowed", MAX_SOCIAL_LINKS)
+            ));
+        }
+        for link in links {
+            if link.platform.is_empty()
+                || link.platform.len() > 20
+                || !link.platform.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return Err(ProfileError::InvalidLink(
+                    "Platform must be 1-20 letters or numbers".to_string()
+                ));
+            }
+            if !Self::is_valid_url(&link.url, &["https://"]) {
+                return Err(ProfileError::InvalidLink(
+                    "URL must start with https:// and be at most 200 characters".to_string()
+                ));
+            }
+        }
+        Ok(())
+    }
+    
+    /// Check that a name is neither reserved nor held by another owner
+    async fn check_name_available(&self, owner: &str, name: &str) -> Result<(), ProfileError> {
+        let key = PlayerProfileState::normalize_name(name);
         
-        // Check if profile already exists
-        if self.state.profiles.get(owner).await
+        if self.state.reserved_names.contains_key(&key).await
             .map_err(|e| ProfileError::StateError(e.to_string()))?
-            .is_some() 
         {
-            return Err(ProfileError::ProfileExists);
+            return Err(ProfileError::NameReserved);
         }
         
-        // Create new profile
-        let profile = PlayerProfile {
-            name: name.trim().to_string(),
-            created_at: self.current_time_ms(),
-            xp: 0,
-            games_played: 0,
-            wins: 0,
-        };
-        
-        // Store the profile
-        self.state.profiles.insert(owner, profile.clone())
+        let holder = self.state.name_index.get(&key).await
             .map_err(|e| ProfileError::StateError(e.to_string()))?;
-        
-        Ok(profile)
+        match holder {
+            Some(holder) if holder != owner => Err(ProfileError::NameTaken),
+            _ => Ok(()),
+        }
     }
     
-    /// Update profile stats
-    async fn update_stats(
-        &mut self, 
-        owner: &str, 
-        xp_delta: u64, 
-        games_delta: u64, 
-        wins_delta: u64
-    ) -> Result<PlayerProfile, ProfileError> {
-        // Get existing profile
-        let mut profile = self.state.profiles.get(owner).await
+    /// Check that the caller is the admin
+    fn check_admin(&self, owner: &str) -> Result<(), ProfileError> {
+        if self.state.admin.get() != owner {
+            return Err(ProfileError::AdminOnly);
+        }
+        Ok(())
+    }
+    
+    /// Reserve or release a name (admin only)
+    async fn set_name_reserved(&mut self, owner: &str, name: &str, reserved: bool) -> Result<(), ProfileError> {
+        self.check_admin(owner)?;
+        
+        let key = PlayerProfileState::normalize_name(name);
+        if key.is_empty() {
+            return Err(ProfileError::InvalidName("Name must not be empty".to_string()));
+        }
+        
+        if reserved {
+            let now = self.current_time_ms();
+            self.state.reserved_names.insert(&key, now)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        } else {
+            self.state.reserved_names.remove(&key)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Hand the admin role to another account owner (admin only)
+    async fn transfer_admin(&mut self, owner: &str, new_admin: &str) -> Result<String, ProfileError> {
+        self.check_admin(owner)?;
+        
+        let new_admin = self.state.resolve_owner(new_admin.trim()).await;
+        if new_admin.is_empty() {
+            return Err(ProfileError::InvalidAdmin);
+        }
+        self.state.admin.set(new_admin.clone());
+        
+        Ok(new_admin)
+    }
+    
+    /// Allow a game application to update stats (admin only)
+    async fn register_game(&mut self, owner: &str, application_id: ApplicationId, name: String) -> Result<(), ProfileError> {
+        self.check_admin(owner)?;
+        
+        let game = RegisteredGame {
+            application_id,
+            name,
+            registered_at: self.current_time_ms(),
+        };
+        self.state.registered_games.insert(&application_id, game)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Remove a game application from the registry (admin only)
+    async fn unregister_game(&mut self, owner: &str, application_id: ApplicationId) -> Result<(), ProfileError> {
+        self.check_admin(owner)?;
+        
+        self.state.registered_games.remove(&application_id)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Check that a game application is in the registry
+    async fn check_registered_game(&self, game: ApplicationId) -> Result<(), ProfileError> {
+        if self.state.registered_games.contains_key(&game).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            Ok(())
+        } else {
+            Err(ProfileError::Unauthorized)
+        }
+    }
+    
+    /// Add a badge to the catalog or update its details (admin only);
+    /// the creation time and holder count are filled in here
+    async fn define_badge(&mut self, owner: &str, badge: Badge) -> Result<(), ProfileError> {
+        self.check_admin(owner)?;
+        
+        let Badge { id, title, icon_url, rarity, criteria, games, .. } = badge;
+        let id = id.trim().to_string();
+        let title = title.trim().to_string();
+        let criteria = criteria.trim().to_string();
+        if id.is_empty()
+            || id.len() > 32
+            || !id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        {
+            return Err(ProfileError::InvalidBadge(
+                "ID must be 1-32 lowercase letters, digits, '-' or '_'".to_string()
+            ));
+        }
+        if title.is_empty() || title.chars().count() > 40 {
+            return Err(ProfileError::InvalidBadge("Title must be 1-40 characters".to_string()));
+        }
+        if !Self::is_valid_url(&icon_url, &["https://", "ipfs://"]) {
+            return Err(ProfileError::InvalidBadge(
+                "Icon URL must start with https:// or ipfs:// and be at most 200 characters".to_string()
+            ));
+        }
+        if criteria.chars().count() > 200 {
+            return Err(ProfileError::InvalidBadge("Criteria must be at most 200 characters".to_string()));
+        }
+        if games.is_empty() {
+            return Err(ProfileError::InvalidBadge("At least one game must be allowed to award it".to_string()));
+        }
+        for game in &games {
+            self.check_registered_game(*game).await?;
+        }
+        
+        // Updating a badge keeps its creation time and holder count
+        let existing = self.state.badges.get(&id).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        let now = self.current_time_ms();
+        let badge = Badge {
+            id: id.clone(),
+            title,
+            icon_url,
+            rarity,
+            criteria,
+            games,
+            created_at: existing.as_ref().map_or(now, |badge| badge.created_at),
+            holders: existing.map_or(0, |badge| badge.holders),
+        };
+        self.state.badges.insert(&id, badge)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Award a badge to a profile on behalf of a game
+    async fn award_badge(&mut self, owner: &str, game: ApplicationId, badge_id: &str) -> Result<(), ProfileError> {
+        // Only registered games may award badges, and only the badges listing them
+        self.check_registered_game(game).await?;
+        
+        if !self.state.profiles.contains_key(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::ProfileNotFound);
+        }
+        let mut badge = self.state.badges.get(badge_id).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::BadgeNotFound)?;
+        if !badge.games.contains(&game) {
+            return Err(ProfileError::BadgeGameNotAllowed);
+        }
+        
+        let mut badges = self.state.get_player_badges(owner).await;
+        if badges.iter().any(|awarded| awarded.badge_id == badge_id) {
+            return Err(ProfileError::BadgeAlreadyAwarded);
+        }
+        badges.push(AwardedBadge {
+            badge_id: badge_id.to_string(),
+            game,
+            awarded_at: self.current_time_ms(),
+        });
+        badge.holders += 1;
+        
+        self.state.player_badges.insert(owner, badges)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.badge_holders.insert(PlayerProfileState::holder_key(badge_id, owner), ());
+        self.state.badges.insert(badge_id, badge)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Take a badge back from a profile, unpinning it (admin only)
+    async fn revoke_badge(&mut self, owner: &str, player: &str, badge_id: &str) -> Result<(), ProfileError> {
+        self.check_admin(owner)?;
+        let player = self.state.resolve_owner(player).await;
+        let player = player.as_str();
+        
+        let mut badges = self.state.get_player_badges(player).await;
+        let count = badges.len();
+        badges.retain(|awarded| awarded.badge_id != badge_id);
+        if badges.len() == count {
+            return Err(ProfileError::BadgeNotOwned);
+        }
+        
+        if badges.is_empty() {
+            self.state.player_badges.remove(player)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        } else {
+            self.state.player_badges.insert(player, badges)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        self.state.badge_holders.remove(PlayerProfileState::holder_key(badge_id, player));
+        if let Some(mut badge) = self.state.badges.get(badge_id).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            badge.holders = badge.holders.saturating_sub(1);
+            self.state.badges.insert(badge_id, badge)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        if let Some(mut profile) = self.state.profiles.get(player).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            if profile.showcase.iter().any(|pinned| pinned == badge_id) {
+                profile.showcase.retain(|pinned| pinned != badge_id);
+                self.state.profiles.insert(player, profile)
+                    .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Pin owned badges to a profile
+    async fn set_showcase(&mut self, owner: &str, badge_ids: Vec<String>) -> Result<(), ProfileError> {
+        if badge_ids.len() > MAX_SHOWCASE_BADGES {
+            return Err(ProfileError::TooManyShowcaseBadges(MAX_SHOWCASE_BADGES));
+        }
+        
+        let mut profile = self.state.profiles.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::ProfileNotFound)?;
+        
+        let owned = self.state.get_player_badges(owner).await;
+        for (i, badge_id) in badge_ids.iter().enumerate() {
+            if badge_ids[..i].contains(badge_id) {
+                return Err(ProfileError::InvalidBadge("Showcase lists a badge twice".to_string()));
+            }
+            if !owned.iter().any(|awarded| &awarded.badge_id == badge_id) {
+                return Err(ProfileError::BadgeNotOwned);
+            }
+        }
+        
+        profile.showcase = badge_ids;
+        self.state.profiles.insert(owner, profile)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Register a new profile
+    async fn register_profile(&mut self, owner: &str, name: String) -> Result<PlayerProfile, ProfileError> {
+        // Validate name
+        Self::validate_name(&name)?;
+        
+        // Check if profile already exists
+        if self.state.profiles.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .is_some() 
+        {
+            return Err(ProfileError::ProfileExists);
+        }
+        
+        // Names are unique regardless of case
+        self.check_name_available(owner, &name).await?;
+        
+        // Create new profile
+        let profile = PlayerProfile {
+            name: name.trim().to_string(),
+            created_at: self.current_time_ms(),
+            xp: 0,
+            games_played: 0,
+            wins: 0,
+            name_changed_at: None,
+            ..Default::default()
+        };
+        
+        // Store the profile and claim its name
+        self.state.profiles.insert(owner, profile.clone())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(owner, None, Some(&profile)).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.name_index.insert(&PlayerProfileState::normalize_name(&profile.name), owner.to_string())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(profile)
+    }
+    
+    /// Update profile stats on behalf of the reporting game
+    async fn update_stats(
+        &mut self, 
+        owner: &str, 
+        game: ApplicationId,
+        xp_delta: u64, 
+        games_delta: u64, 
+        wins_delta: u64
+    ) -> Result<PlayerProfile, ProfileError> {
+        // Only registered games may report stats
+        self.check_registered_game(game).await?;
+        
+        // Get existing profile
+        let mut profile = self.state.profiles.get(owner).await
             .map_err(|e| ProfileError::StateError(e.to_string()))?
             .ok_or(ProfileError::ProfileNotFound)?;
         
         // Update stats
+        let before = profile.clone();
+        let old_xp = profile.xp;
         profile.xp = profile.xp.saturating_add(xp_delta);
         profile.games_played = profile.games_played.saturating_add(games_delta);
         profile.wins = profile.wins.saturating_add(wins_delta);
         
+        // Attribute the deltas to the reporting game
+        let mut game_stats = self.state.get_game_stat(owner, &game).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .unwrap_or_else(|| GameStats::new(game));
+        game_stats.xp = game_stats.xp.saturating_add(xp_delta);
+        game_stats.games_played = game_stats.games_played.saturating_add(games_delta);
+        game_stats.wins = game_stats.wins.saturating_add(wins_delta);
+        game_stats.updated_at = self.current_time_ms();
+        
         // Store updated profile
         self.state.profiles.insert(owner, profile.clone())
             .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(owner, Some(&before), Some(&profile)).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.save_game_stats(owner, game_stats, profile.private).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        self.emit_level_up(owner, old_xp, profile.xp, Some(game));
+        
+        Ok(profile)
+    }
+    
+    /// Overwrite a player's stats for one game (admin only)
+    async fn set_game_stats(
+        &mut self,
+        owner: &str,
+        player: &str,
+        game: ApplicationId,
+        xp: u64,
+        games_played: u64,
+        wins: u64,
+    ) -> Result<PlayerProfile, ProfileError> {
+        self.check_admin(owner)?;
+        let player = self.state.resolve_owner(player).await;
+        let player = player.as_str();
+        
+        // Get existing profile
+        let mut profile = self.state.profiles.get(player).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::ProfileNotFound)?;
+        
+        let previous = self.state.get_game_stat(player, &game).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .unwrap_or_else(|| GameStats::new(game));
+        
+        // Swap the game's old contribution for the new one in the totals
+        let before = profile.clone();
+        let old_xp = profile.xp;
+        profile.xp = profile.xp.saturating_sub(previous.xp).saturating_add(xp);
+        profile.games_played = profile.games_played.saturating_sub(previous.games_played).saturating_add(games_played);
+        profile.wins = profile.wins.saturating_sub(previous.wins).saturating_add(wins);
+        
+        let game_stats = GameStats {
+            game,
+            xp,
+            games_played,
+            wins,
+            updated_at: self.current_time_ms(),
+        };
+        
+        // Store updated profile
+        self.state.profiles.insert(player, profile.clone())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(player, Some(&before), Some(&profile)).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.save_game_stats(player, game_stats, profile.private).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        self.emit_level_up(player, old_xp, profile.xp, None);
         
         Ok(profile)
     }
     
+    /// Index a batch of profiles into the rankings (admin only)
+    ///
+    /// Profiles that are already ranked are left as they are, so batches can
+    /// be repeated safely. Returns the owner to continue after, if any are left.
+    async fn backfill_rankings(
+        &mut self,
+        owner: &str,
+        after: Option<String>,
+        limit: u32,
+    ) -> Result<Option<String>, ProfileError> {
+        self.check_admin(owner)?;
+        
+        if let Some(after) = &after {
+            if !self.state.profiles.contains_key(after).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?
+            {
+                return Err(ProfileError::ProfileNotFound);
+            }
+        }
+        
+        let limit = limit as usize;
+        let profiles = self.state.profiles_after(after.as_deref(), limit).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        let full = profiles.len() == limit;
+        let mut last = None;
+        for (player, profile) in profiles {
+            self.state.update_rankings(&player, None, Some(&profile)).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            last = Some(player);
+        }
+        
+        Ok(last.filter(|_| full))
+    }
+    
+    /// Emit a level-up event if the XP change crossed a level threshold
+    fn emit_level_up(&mut self, owner: &str, old_xp: u64, new_xp: u64, game: Option<ApplicationId>) {
+        let curve = self.runtime.application_parameters().level_curve;
+        let old_level = curve.level_for(old_xp);
+        let new_level = curve.level_for(new_xp);
+        if new_level <= old_level {
+            return;
+        }
+        
+        let event = ProfileEvent::LevelUp {
+            owner: owner.to_string(),
+            old_level,
+            new_level,
+            xp: new_xp,
+            game,
+            timestamp: self.current_time_ms(),
+        };
+        self.runtime.emit(StreamName::from(LEVEL_UP_STREAM), &event);
+    }
+    
+    /// Use a meme as avatar; `game` has checked that the owner holds it
+    async fn set_meme_avatar(&mut self, owner: &str, game: ApplicationId, meme_id: u64, image_url: String) -> Result<(), ProfileError> {
+        self.check_registered_game(game).await?;
+        
+        if !Self::is_valid_url(&image_url, &["https://", "ipfs://"]) {
+            return Err(ProfileError::InvalidAvatar(
+                "URL must start with https:// or ipfs:// and be at most 200 characters".to_string()
+            ));
+        }
+        let mut profile = self.state.profiles.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::ProfileNotFound)?;
+        profile.avatar = Some(Avatar {
+            url: Some(image_url),
+            content_hash: None,
+            meme: Some(MemeAvatar { game, meme_id }),
+        });
+        self.state.profiles.insert(owner, profile)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Update profile details
+    async fn update_details(
+        &mut self,
+        owner: &str,
+        avatar: Option<Avatar>,
+        bio: Option<String>,
+        region: Option<String>,
+        links: Option<Vec<SocialLink>>,
+        private: Option<bool>,
+    ) -> Result<PlayerProfile, ProfileError> {
+        // Get existing profile
+        let mut profile = self.state.profiles.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::ProfileNotFound)?;
+        
+        let before = profile.clone();
+        
+        // Nothing is stored unless every field is valid
+        if let Some(avatar) = avatar {
+            if avatar == Avatar::default() {
+                profile.avatar = None;
+            } else {
+                Self::validate_avatar(&avatar)?;
+                profile.avatar = Some(avatar);
+            }
+        }
+        if let Some(bio) = bio {
+            let bio = bio.trim().to_string();
+            Self::validate_bio(&bio)?;
+            profile.bio = bio;
+        }
+        if let Some(region) = region {
+            let region = region.trim().to_uppercase();
+            if region.is_empty() {
+                profile.region = None;
+            } else {
+                Self::validate_region(&region)?;
+                profile.region = Some(region);
+            }
+        }
+        if let Some(links) = links {
+            Self::validate_links(&links)?;
+            profile.links = links;
+        }
+        if let Some(private) = private {
+            profile.private = private;
+        }
+        
+        // Store updated profile
+        self.state.profiles.insert(owner, profile.clone())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(owner, Some(&before), Some(&profile)).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        // Per-game leaderboards only list public profiles
+        if profile.private != before.private {
+            for stats in self.state.get_game_stats(owner).await {
+                let (old, new) = if profile.private { (Some(&stats), None) } else { (None, Some(&stats)) };
+                self.state.update_game_rankings(owner, old, new)
+                    .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            }
+        }
+        
+        Ok(profile)
+    }
+    
+    /// Apply a relationship change and wrap the outcome as an operation result
+    async fn relationship_result(&mut self, owner: &str, player: &str, action: SocialAction) -> OperationResult {
+        match self.update_relationship(owner, player, action).await {
+            Ok(()) => OperationResult::RelationshipsUpdated,
+            Err(e) => OperationResult::Error(e.to_string()),
+        }
+    }
+    
+    /// Change the relationship between the caller and another player,
+    /// keeping both sides in sync
+    async fn update_relationship(&mut self, owner: &str, player: &str, action: SocialAction) -> Result<(), ProfileError> {
+        let player = self.state.resolve_owner(player).await;
+        let player = player.as_str();
+        if owner == player {
+            return Err(ProfileError::CannotTargetSelf);
+        }
+        
+        // Relationships only exist between profiles
+        for address in [owner, player] {
+            if !self.state.profiles.contains_key(address).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?
+            {
+                return Err(ProfileError::ProfileNotFound);
+            }
+        }
+        
+        let old_mine = self.state.get_relation(owner, player).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        let old_theirs = self.state.get_relation(player, owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        let mut mine = old_mine;
+        let mut theirs = old_theirs;
+        
+        // Follows and friendships need no block either way
+        let connecting = matches!(
+            action,
+            SocialAction::Follow | SocialAction::SendFriendRequest | SocialAction::AcceptFriendRequest
+        );
+        if connecting && (mine.blocked || mine.blocked_by) {
+            return Err(ProfileError::Blocked);
+        }
+        
+        match action {
+            SocialAction::Follow => {
+                if mine.following {
+                    return Err(ProfileError::AlreadyFollowing);
+                }
+                mine.following = true;
+                theirs.follower = true;
+            }
+            SocialAction::Unfollow => {
+                if !mine.following {
+                    return Err(ProfileError::NotFollowing);
+                }
+                mine.following = false;
+                theirs.follower = false;
+            }
+            SocialAction::SendFriendRequest => {
+                if mine.friend {
+                    return Err(ProfileError::AlreadyFriends);
+                }
+                if mine.incoming_request {
+                    // They asked first, so this accepts their request
+                    mine.incoming_request = false;
+                    theirs.outgoing_request = false;
+                    mine.friend = true;
+                    theirs.friend = true;
+                } else {
+                    if mine.outgoing_request {
+                        return Err(ProfileError::RequestAlreadySent);
+                    }
+                    mine.outgoing_request = true;
+                    theirs.incoming_request = true;
+                }
+            }
+            SocialAction::CancelFriendRequest => {
+                if !mine.outgoing_request {
+                    return Err(ProfileError::NoFriendRequest);
+                }
+                mine.outgoing_request = false;
+                theirs.incoming_request = false;
+            }
+            SocialAction::AcceptFriendRequest => {
+                if !mine.incoming_request {
+                    return Err(ProfileError::NoFriendRequest);
+                }
+                mine.incoming_request = false;
+                theirs.outgoing_request = false;
+                mine.friend = true;
+                theirs.friend = true;
+            }
+            SocialAction::DeclineFriendRequest => {
+                if !mine.incoming_request {
+                    return Err(ProfileError::NoFriendRequest);
+                }
+                mine.incoming_request = false;
+                theirs.outgoing_request = false;
+            }
+            SocialAction::RemoveFriend => {
+                if !mine.friend {
+                    return Err(ProfileError::NotFriends);
+                }
+                mine.friend = false;
+                theirs.friend = false;
+            }
+            SocialAction::Block => {
+                if mine.blocked {
+                    return Err(ProfileError::AlreadyBlocked);
+                }
+                mine.sever();
+                theirs.sever();
+                mine.blocked = true;
+                theirs.blocked_by = true;
+            }
+            SocialAction::Unblock => {
+                if !mine.blocked {
+                    return Err(ProfileError::NotBlocked);
+                }
+                mine.blocked = false;
+                theirs.blocked_by = false;
+            }
+        }
+        
+        // Only the lists the caller grows themselves are bounded, so nobody
+        // can be kept from following a popular player
+        let mut counts = self.state.get_relationship_counts(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        counts.apply(&old_mine, &mine);
+        if counts.longest_own_list() > MAX_RELATIONSHIPS {
+            return Err(ProfileError::TooManyRelationships(MAX_RELATIONSHIPS));
+        }
+        
+        self.state.save_relation(owner, player, &old_mine, mine).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.save_relation(player, owner, &old_theirs, theirs).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Delete a profile and everything attached to it
+    async fn delete_profile(&mut self, owner: &str) -> Result<(), ProfileError> {
+        let profile = self.state.profiles.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::ProfileNotFound)?;
+        
+        // Free the name and drop the profile's own records
+        self.state.name_index.remove(&PlayerProfileState::normalize_name(&profile.name))
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.name_history.remove(owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        for stats in self.state.get_game_stats(owner).await {
+            self.state.remove_game_stats(owner, &stats, profile.private)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        self.state.pending_transfers.remove(owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        // Remove the player from everyone else's lists
+        let relations = self.state.get_relations(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        for (player, mine) in relations {
+            let theirs = self.state.get_relation(&player, owner).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_relation(&player, owner, &theirs, Relation::default()).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_relation(owner, &player, &mine, Relation::default()).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        // Give back the profile's badges
+        for awarded in self.state.get_player_badges(owner).await {
+            self.state.badge_holders.remove(PlayerProfileState::holder_key(&awarded.badge_id, owner));
+            if let Some(mut badge) = self.state.badges.get(&awarded.badge_id).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?
+            {
+                badge.holders = badge.holders.saturating_sub(1);
+                self.state.badges.insert(&awarded.badge_id, badge)
+                    .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            }
+        }
+        self.state.player_badges.remove(owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        // Drop the link requests it made and release linked identities
+        for identity in self.state.get_link_requests(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            self.state.remove_link_request(owner, &identity)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        for identity in self.state.get_linked_identities(owner).await {
+            self.state.identity_links.remove(&identity)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        self.state.linked_identities.remove(owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        self.state.profiles.remove(owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(owner, Some(&profile), None).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Start moving a profile to a new account owner
+    async fn request_transfer(&mut self, owner: &str, new_owner: String) -> Result<(), ProfileError> {
+        let new_owner = new_owner.trim().to_string();
+        if new_owner == owner {
+            return Err(ProfileError::CannotTargetSelf);
+        }
+        if !self.state.profiles.contains_key(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::ProfileNotFound);
+        }
+        
+        self.state.pending_transfers.insert(owner, new_owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Withdraw a pending profile transfer
+    async fn cancel_transfer(&mut self, owner: &str) -> Result<(), ProfileError> {
+        if !self.state.pending_transfers.contains_key(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::TransferNotRequested);
+        }
+        
+        self.state.pending_transfers.remove(owner)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
+    /// Move a profile and everything attached to it to the new owner
+    async fn accept_transfer(&mut self, from: &str, new_owner: &str) -> Result<(), ProfileError> {
+        let requested = self.state.pending_transfers.get(from).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        if requested.as_deref() != Some(new_owner) {
+            return Err(ProfileError::TransferNotRequested);
+        }
+        
+        // The new owner must not have a profile of its own or belong to another one
+        if self.state.profiles.contains_key(new_owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            return Err(ProfileError::IdentityHasProfile);
+        }
+        let mut linked = self.state.get_linked_identities(from).await;
+        if self.state.resolve_owner(new_owner).await != new_owner {
+            if !linked.iter().any(|identity| identity == new_owner) {
+                return Err(ProfileError::AlreadyLinked);
+            }
+            // A linked identity becomes the canonical owner
+            linked.retain(|identity| identity != new_owner);
+            self.state.identity_links.remove(new_owner)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        let profile = self.state.profiles.get(from).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .ok_or(ProfileError::ProfileNotFound)?;
+        self.state.pending_transfers.remove(from)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        // Move the name and the profile's own records
+        self.state.name_index.insert(&PlayerProfileState::normalize_name(&profile.name), new_owner.to_string())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        if let Some(history) = self.state.name_history.get(from).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            self.state.name_history.insert(new_owner, history)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.name_history.remove(from)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        for stats in self.state.get_game_stats(from).await {
+            self.state.remove_game_stats(from, &stats, profile.private)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_game_stats(new_owner, stats, profile.private).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        // Rename the player in everyone else's lists
+        let relations = self.state.get_relations(from).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        for (player, mine) in relations {
+            let theirs = self.state.get_relation(&player, from).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_relation(&player, from, &theirs, Relation::default()).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_relation(&player, new_owner, &Relation::default(), theirs).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_relation(from, &player, &mine, Relation::default()).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.save_relation(new_owner, &player, &Relation::default(), mine).await
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        // Move the profile's badges
+        let badges = self.state.get_player_badges(from).await;
+        for awarded in &badges {
+            self.state.badge_holders.remove(PlayerProfileState::holder_key(&awarded.badge_id, from));
+            self.state.badge_holders.insert(PlayerProfileState::holder_key(&awarded.badge_id, new_owner), ());
+        }
+        if !badges.is_empty() {
+            self.state.player_badges.remove(from)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            self.state.player_badges.insert(new_owner, badges)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        
+        // Keep the link requests it made pending for the new owner
+        for identity in self.state.get_link_requests(from).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+        {
+            self.state.remove_link_request(from, &identity)
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            if identity != new_owner {
+                self.state.add_link_request(new_owner, &identity)
+                    .map_err(|e| ProfileError::StateError(e.to_string()))?;
+            }
+        }
+        
+        // Point the remaining linked identities at the new owner
+        for identity in &linked {
+            self.state.identity_links.insert(identity, new_owner.to_string())
+                .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        }
+        self.state.linked_identities.remove(from)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.linked_identities.insert(new_owner, linked)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        // An admin moving to a new key keeps the role
+        if self.state.admin.get() == from {
+            self.state.admin.set(new_owner.to_string());
+        }
+        
+        self.state.update_rankings(from, Some(&profile), None).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.update_rankings(new_owner, None, Some(&profile)).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.profiles.remove(from)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.profiles.insert(new_owner, profile)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        Ok(())
+    }
+    
     /// Update profile name
     async fn update_name(&mut self, owner: &str, name: String) -> Result<PlayerProfile, ProfileError> {
         // Validate name
@@ -187,8 +1544,45 @@ impl PlayerProfileContract {
             .map_err(|e| ProfileError::StateError(e.to_string()))?
             .ok_or(ProfileError::ProfileNotFound)?;
         
+        let new_name = name.trim().to_string();
+        if new_name == profile.name {
+            return Ok(profile);
+        }
+        
+        // Enforce the cooldown between name changes
+        let now = self.current_time_ms();
+        if let Some(changed_at) = profile.name_changed_at {
+            let cooldown = self.runtime.application_parameters().name_change_cooldown_ms;
+            let next_change = changed_at.saturating_add(cooldown);
+            if now < next_change {
+                return Err(ProfileError::NameChangeCooldown((next_change - now).div_ceil(1000)));
+            }
+        }
+        
+        // Names are unique regardless of case
+        self.check_name_available(owner, &new_name).await?;
+        
+        // Move the name index entry to the new name
+        self.state.name_index.remove(&PlayerProfileState::normalize_name(&profile.name))
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        self.state.name_index.insert(&PlayerProfileState::normalize_name(&new_name), owner.to_string())
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
+        // Record the change in the profile's name history
+        let mut history = self.state.name_history.get(owner).await
+            .map_err(|e| ProfileError::StateError(e.to_string()))?
+            .unwrap_or_default();
+        history.push(NameChange {
+            old_name: profile.name.clone(),
+            new_name: new_name.clone(),
+            changed_at: now,
+        });
+        self.state.name_history.insert(owner, history)
+            .map_err(|e| ProfileError::StateError(e.to_string()))?;
+        
         // Update name
-        profile.name = name.trim().to_string();
+        profile.name = new_name;
+        profile.name_changed_at = Some(now);
         
         // Store updated profile
         self.state.profiles.insert(owner, profile.clone())
@@ -197,3 +1591,384 @@ impl PlayerProfileContract {
         Ok(profile)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::{
+        linera_base_types::{AccountOwner, ApplicationId, ChainId, CryptoHash, Timestamp},
+        util::BlockingWait,
+        views::View,
+        Contract, ContractRuntime,
+    };
+    use player_profile::{Avatar, MemeAvatar, Operation, OperationResult, PlayerProfile, ProfileError, ProfileParameters, ProfileSort};
+    
+    use super::{PlayerProfileContract, PlayerProfileState};
+    
+    fn owner(name: &str) -> AccountOwner {
+        AccountOwner::from(CryptoHash::test_hash(name))
+    }
+    
+    fn game() -> ApplicationId {
+        ApplicationId::new(CryptoHash::test_hash("game"))
+    }
+    
+    /// Instantiate the contract on its creator chain, with `admin` as the creator
+    fn create_contract(admin: AccountOwner) -> PlayerProfileContract {
+        let chain_id = ChainId(CryptoHash::test_hash("hub"));
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(ProfileParameters::default())
+            .with_chain_id(chain_id)
+            .with_application_creator_chain_id(chain_id)
+            .with_authenticated_signer(admin)
+            .with_authenticated_caller_id(None)
+            .with_system_time(Timestamp::from(1_000_000));
+        let mut contract = PlayerProfileContract {
+            state: PlayerProfileState::load(runtime.root_view_storage_context())
+                .blocking_wait()
+                .expect("Failed to read from mock key value store"),
+            runtime,
+        };
+        contract.instantiate(()).blocking_wait();
+        contract
+    }
+    
+    fn register_game(contract: &mut PlayerProfileContract) -> OperationResult {
+        contract.execute_operation(Operation::RegisterGame {
+            application_id: game(),
+            name: "Typing Arena".to_string(),
+        }).blocking_wait()
+    }
+    
+    fn report_stats(contract: &mut PlayerProfileContract) -> OperationResult {
+        contract.execute_operation(Operation::UpdateStats {
+            xp_delta: 10,
+            games_delta: 1,
+            wins_delta: 1,
+        }).blocking_wait()
+    }
+    
+    fn is_error(result: &OperationResult, error: ProfileError) -> bool {
+        matches!(result, OperationResult::Error(message) if *message == error.to_string())
+    }
+    
+    /// Register a profile named `name` for `player` and make them the signer
+    fn register_player(contract: &mut PlayerProfileContract, player: AccountOwner, name: &str) {
+        contract.runtime.set_authenticated_signer(Some(player));
+        let result = contract.execute_operation(Operation::Register {
+            name: name.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::ProfileCreated));
+    }
+    
+    #[test]
+    fn users_cannot_update_their_own_stats() {
+        let player = owner("player");
+        let mut contract = create_contract(owner("admin"));
+        register_player(&mut contract, player, "player");
+        
+        assert!(is_error(&report_stats(&mut contract), ProfileError::Unauthorized));
+        let profile = contract.state.profiles.get(&player.to_string()).blocking_wait()
+            .unwrap()
+            .unwrap();
+        assert_eq!(profile.xp, 0);
+    }
+    
+    #[test]
+    fn unregistered_games_cannot_update_stats() {
+        let player = owner("player");
+        let mut contract = create_contract(owner("admin"));
+        register_player(&mut contract, player, "player");
+        
+        contract.runtime.set_authenticated_caller_id(Some(game()));
+        assert!(is_error(&report_stats(&mut contract), ProfileError::Unauthorized));
+        let profile = contract.state.profiles.get(&player.to_string()).blocking_wait()
+            .unwrap()
+            .unwrap();
+        assert_eq!(profile.xp, 0);
+    }
+    
+    #[test]
+    fn registered_games_update_the_signers_stats() {
+        let admin = owner("admin");
+        let player = owner("player");
+        let mut contract = create_contract(admin);
+        assert!(matches!(register_game(&mut contract), OperationResult::GamesUpdated));
+        register_player(&mut contract, player, "player");
+        
+        contract.runtime.set_authenticated_caller_id(Some(game()));
+        let result = report_stats(&mut contract);
+        assert!(matches!(result, OperationResult::StatsUpdated { new_xp: 10, new_games: 1, new_wins: 1, .. }));
+        
+        let stats = contract.state.get_game_stats(&player.to_string()).blocking_wait();
+        assert_eq!(stats.len(), 1);
+        assert_eq!((stats[0].game, stats[0].xp), (game(), 10));
+    }
+    
+    #[test]
+    fn only_registered_games_set_meme_avatars() {
+        let admin = owner("admin");
+        let player = owner("player");
+        let mut contract = create_contract(admin);
+        assert!(matches!(register_game(&mut contract), OperationResult::GamesUpdated));
+        register_player(&mut contract, player, "player");
+        let set_meme_avatar = Operation::SetMemeAvatar {
+            meme_id: 7,
+            image_url: "https://memes.example/7.png".to_string(),
+        };
+        
+        let result = contract.execute_operation(set_meme_avatar.clone()).blocking_wait();
+        assert!(is_error(&result, ProfileError::Unauthorized));
+        let claimed = Avatar {
+            url: Some("https://memes.example/7.png".to_string()),
+            content_hash: None,
+            meme: Some(MemeAvatar { game: game(), meme_id: 7 }),
+        };
+        let result = contract.execute_operation(Operation::UpdateDetails {
+            avatar: Some(claimed.clone()),
+            bio: None,
+            region: None,
+            links: None,
+            private: None,
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::Error(_)));
+        
+        contract.runtime.set_authenticated_caller_id(Some(game()));
+        let result = contract.execute_operation(set_meme_avatar).blocking_wait();
+        assert!(matches!(result, OperationResult::ProfileUpdated));
+        let profile = contract.state.profiles.get(&player.to_string()).blocking_wait()
+            .unwrap()
+            .unwrap();
+        assert_eq!(profile.avatar, Some(claimed));
+    }
+    
+    #[test]
+    fn only_the_admin_registers_games() {
+        let admin = owner("admin");
+        let mut contract = create_contract(admin);
+        
+        contract.runtime.set_authenticated_signer(Some(owner("player")));
+        assert!(is_error(&register_game(&mut contract), ProfileError::AdminOnly));
+        assert!(!contract.state.registered_games.contains_key(&game()).blocking_wait().unwrap());
+        
+        contract.runtime.set_authenticated_signer(Some(admin));
+        assert!(matches!(register_game(&mut contract), OperationResult::GamesUpdated));
+        assert!(contract.state.registered_games.contains_key(&game()).blocking_wait().unwrap());
+    }
+    
+    #[test]
+    fn only_the_admin_corrects_stats() {
+        let player = owner("player");
+        let mut contract = create_contract(owner("admin"));
+        register_player(&mut contract, player, "player");
+        
+        let result = contract.execute_operation(Operation::SetGameStats {
+            owner: player.to_string(),
+            game: game(),
+            xp: 50,
+            games_played: 5,
+            wins: 5,
+        }).blocking_wait();
+        assert!(is_error(&result, ProfileError::AdminOnly));
+    }
+    
+    #[test]
+    fn profiles_move_to_a_linked_identity() {
+        let alice = owner("alice");
+        let bob = owner("bob");
+        let mut contract = create_contract(owner("admin"));
+        register_player(&mut contract, alice, "alice");
+        
+        // Link bob's key to alice's profile, then hand the profile to it
+        let result = contract.execute_operation(Operation::RequestLink {
+            identity: bob.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::LinksUpdated));
+        contract.runtime.set_authenticated_signer(Some(bob));
+        let result = contract.execute_operation(Operation::ConfirmLink {
+            profile_owner: alice.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::LinksUpdated));
+        assert_eq!(contract.state.resolve_owner(&bob.to_string()).blocking_wait(), alice.to_string());
+        
+        contract.runtime.set_authenticated_signer(Some(alice));
+        let result = contract.execute_operation(Operation::TransferProfile {
+            new_owner: bob.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::ProfileUpdated));
+        contract.runtime.set_authenticated_signer(Some(bob));
+        let result = contract.execute_operation(Operation::AcceptProfileTransfer {
+            from: alice.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::ProfileTransferred { new_owner } if new_owner == bob.to_string()));
+        
+        let state = &contract.state;
+        assert!(!state.profiles.contains_key(&alice.to_string()).blocking_wait().unwrap());
+        assert!(state.profiles.contains_key(&bob.to_string()).blocking_wait().unwrap());
+        assert_eq!(state.name_index.get("alice").blocking_wait().unwrap(), Some(bob.to_string()));
+        assert_eq!(state.resolve_owner(&bob.to_string()).blocking_wait(), bob.to_string());
+        assert!(state.get_linked_identities(&bob.to_string()).blocking_wait().is_empty());
+        assert!(!state.identity_links.contains_key(&bob.to_string()).blocking_wait().unwrap());
+    }
+    
+    #[test]
+    fn transfers_carry_relations_on_both_sides() {
+        let alice = owner("alice");
+        let bob = owner("bob");
+        let carol = owner("carol");
+        let mut contract = create_contract(owner("admin"));
+        register_player(&mut contract, alice, "alice");
+        register_player(&mut contract, carol, "carol");
+        let result = contract.execute_operation(Operation::Follow {
+            player: alice.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::RelationshipsUpdated));
+        
+        contract.runtime.set_authenticated_signer(Some(alice));
+        contract.execute_operation(Operation::Follow { player: carol.to_string() }).blocking_wait();
+        contract.execute_operation(Operation::TransferProfile { new_owner: bob.to_string() }).blocking_wait();
+        contract.runtime.set_authenticated_signer(Some(bob));
+        contract.execute_operation(Operation::AcceptProfileTransfer { from: alice.to_string() }).blocking_wait();
+        
+        let (alice, bob, carol) = (alice.to_string(), bob.to_string(), carol.to_string());
+        let state = &contract.state;
+        let moved = state.get_relation(&bob, &carol).blocking_wait().unwrap();
+        assert!(moved.following && moved.follower);
+        let theirs = state.get_relation(&carol, &bob).blocking_wait().unwrap();
+        assert!(theirs.following && theirs.follower);
+        assert!(state.get_relation(&carol, &alice).blocking_wait().unwrap().is_empty());
+        assert!(state.get_relations(&alice).blocking_wait().unwrap().is_empty());
+        
+        let counts = state.get_relationship_counts(&bob).blocking_wait().unwrap();
+        assert_eq!((counts.following, counts.followers), (1, 1));
+        let counts = state.get_relationship_counts(&carol).blocking_wait().unwrap();
+        assert_eq!((counts.following, counts.followers), (1, 1));
+        assert_eq!(state.get_relationship_counts(&alice).blocking_wait().unwrap(), Default::default());
+    }
+    
+    #[test]
+    fn the_admin_role_follows_a_transferred_profile() {
+        let admin = owner("admin");
+        let new_key = owner("new-key");
+        let mut contract = create_contract(admin);
+        register_player(&mut contract, admin, "admin");
+        
+        contract.execute_operation(Operation::TransferProfile { new_owner: new_key.to_string() }).blocking_wait();
+        contract.runtime.set_authenticated_signer(Some(new_key));
+        let result = contract.execute_operation(Operation::AcceptProfileTransfer {
+            from: admin.to_string(),
+        }).blocking_wait();
+        assert!(matches!(result, OperationResult::ProfileTransferred { .. }));
+        assert_eq!(contract.state.admin.get(), &new_key.to_string());
+        
+        assert!(matches!(register_game(&mut contract), OperationResult::GamesUpdated));
+        contract.runtime.set_authenticated_signer(Some(admin));
+        assert!(is_error(&register_game(&mut contract), ProfileError::AdminOnly));
+    }
+    
+    /// Store a profile with the given XP straight into the state, keeping the rankings in sync
+    fn set_profile(contract: &mut PlayerProfileContract, owner: &str, xp: u64, private: bool) -> PlayerProfile {
+        let profile = PlayerProfile {
+            name: owner.to_string(),
+            xp,
+            private,
+            ..PlayerProfile::default()
+        };
+        let previous = contract.state.profiles.get(owner).blocking_wait().unwrap();
+        contract.state.profiles.insert(owner, profile.clone()).unwrap();
+        contract.state.update_rankings(owner, previous.as_ref(), Some(&profile)).blocking_wait().unwrap();
+        profile
+    }
+    
+    fn rank(contract: &PlayerProfileContract, owner: &str) -> Option<u32> {
+        contract.state.get_rank(owner, ProfileSort::Xp).blocking_wait().unwrap()
+    }
+    
+    fn page(contract: &PlayerProfileContract, cursor: Option<&str>, limit: usize) -> Vec<String> {
+        contract.state.ranked_owners(ProfileSort::Xp, cursor, limit).blocking_wait()
+            .unwrap()
+            .expect("Cursor should be valid")
+    }
+    
+    #[test]
+    fn ties_are_ranked_by_owner() {
+        let mut contract = create_contract(owner("admin"));
+        for name in ["c", "a", "b"] {
+            set_profile(&mut contract, name, 100, false);
+        }
+        set_profile(&mut contract, "d", 200, false);
+        
+        assert_eq!(page(&contract, None, 10), ["d", "a", "b", "c"]);
+        assert_eq!([rank(&contract, "d"), rank(&contract, "a"), rank(&contract, "c")], [Some(1), Some(2), Some(4)]);
+        
+        // A cursor in the middle of a tie resumes with the next owner of the same score
+        let a = contract.state.profiles.get("a").blocking_wait().unwrap().unwrap();
+        let cursor = PlayerProfileState::encode_cursor(ProfileSort::Xp, &a, "a");
+        assert_eq!(page(&contract, Some(&cursor), 10), ["b", "c"]);
+    }
+    
+    #[test]
+    fn cursors_resume_across_byte_boundaries() {
+        let mut contract = create_contract(owner("admin"));
+        // Inverted big-endian, 256 and 255 differ in the second to last byte,
+        // 65_536 and 65_535 in the third to last one
+        let scores = [70_000, 65_536, 65_535, 256, 255, 1, 0];
+        for (i, xp) in scores.into_iter().enumerate() {
+            set_profile(&mut contract, &format!("player-{i}"), xp, false);
+        }
+        
+        let mut cursor = None;
+        let mut seen = Vec::new();
+        loop {
+            let owners = page(&contract, cursor.as_deref(), 2);
+            let Some(last) = owners.last().cloned() else {
+                break;
+            };
+            seen.extend(owners);
+            let profile = contract.state.profiles.get(&last).blocking_wait().unwrap().unwrap();
+            cursor = Some(PlayerProfileState::encode_cursor(ProfileSort::Xp, &profile, &last));
+        }
+        let expected = (0..scores.len()).map(|i| format!("player-{i}")).collect::<Vec<_>>();
+        assert_eq!(seen, expected);
+        for (i, owner) in expected.iter().enumerate() {
+            assert_eq!(rank(&contract, owner), Some(i as u32 + 1));
+        }
+    }
+    
+    #[test]
+    fn private_profiles_are_not_ranked() {
+        let mut contract = create_contract(owner("admin"));
+        set_profile(&mut contract, "public", 100, false);
+        set_profile(&mut contract, "hidden", 500, true);
+        
+        assert_eq!(page(&contract, None, 10), ["public"]);
+        assert_eq!(rank(&contract, "hidden"), None);
+        assert_eq!(rank(&contract, "public"), Some(1));
+        
+        set_profile(&mut contract, "hidden", 500, false);
+        assert_eq!(page(&contract, None, 10), ["hidden", "public"]);
+        assert_eq!(rank(&contract, "public"), Some(2));
+        
+        set_profile(&mut contract, "hidden", 500, true);
+        assert_eq!(rank(&contract, "public"), Some(1));
+    }
+    
+    #[test]
+    fn ranks_follow_score_updates() {
+        let mut contract = create_contract(owner("admin"));
+        set_profile(&mut contract, "a", 100, false);
+        set_profile(&mut contract, "b", 50, false);
+        assert_eq!([rank(&contract, "a"), rank(&contract, "b")], [Some(1), Some(2)]);
+        
+        set_profile(&mut contract, "b", 150, false);
+        assert_eq!([rank(&contract, "a"), rank(&contract, "b")], [Some(2), Some(1)]);
+        
+        set_profile(&mut contract, "b", 50, false);
+        assert_eq!([rank(&contract, "a"), rank(&contract, "b")], [Some(1), Some(2)]);
+        
+        // Indexing an already ranked profile again, as a backfill does, counts it once
+        let b = contract.state.profiles.get("b").blocking_wait().unwrap().unwrap();
+        contract.state.update_rankings("b", None, Some(&b)).blocking_wait().unwrap();
+        set_profile(&mut contract, "c", 10, false);
+        assert_eq!(rank(&contract, "c"), Some(3));
+    }
+}
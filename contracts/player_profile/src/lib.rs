@@ -9,18 +9,74 @@
  * - XP (experience points)
  * - Games played count
  * - Wins count
+ * - Unique, case-insensitive names with reservations and a change history
+ * - Stats reported only by registered game applications, with a per-game breakdown
+ * - Levels derived from XP using a level curve set in the application parameters
+ * - Avatar, bio, region, social links and a privacy flag hiding stats from listings
+ * - Follows, mutual friendships and block lists
+ * - Self-service deletion, full exports and two-step transfer to a new owner
+ * - Leaderboards by XP, wins, games played, win rate or age, kept in sorted indexes
+ * - A badge catalog; registered games award badges and players pin a showcase
+ *
+ * Profiles are keyed by the authenticated signer (`AccountOwner`). Further
+ * account owners and chains can be linked to a profile so that any of them
+ * resolves to the same canonical owner.
+ *
+ * All profiles, names and registries live on the application's creator
+ * chain; operations submitted on other chains are forwarded there.
  */
 
-use async_graphql::{Request, Response, SimpleObject};
+use async_graphql::{ComplexObject, Context, InputObject, Request, Response, SimpleObject};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
-    linera_base_types::{ContractAbi, ServiceAbi},
+    linera_base_types::{ApplicationId, ContractAbi, ServiceAbi},
 };
 use serde::{Deserialize, Serialize};
 
 /// The ABI for the Player Profile application
 pub struct PlayerProfileAbi;
 
+/// Messages sent between chains running the application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+    /// An operation submitted on another chain, applied on the creator chain
+    /// for the message's signer and origin chain
+    Operation(Operation),
+    /// Stats a game reported for the message's signer on another chain
+    UpdateStats {
+        /// The game application that reported the stats
+        game: ApplicationId,
+        /// XP to add
+        xp_delta: u64,
+        /// Games played to add
+        games_delta: u64,
+        /// Wins to add
+        wins_delta: u64,
+    },
+    /// A badge a game awarded to the message's signer on another chain
+    AwardBadge {
+        /// The game application that awarded the badge
+        game: ApplicationId,
+        /// ID of the badge in the catalog
+        badge_id: String,
+    },
+    /// A meme a game checked the message's signer owns, to use as avatar
+    SetMemeAvatar {
+        /// The game application the meme is owned in
+        game: ApplicationId,
+        /// ID of the meme in that game
+        meme_id: u64,
+        /// Image URL of the meme
+        image_url: String,
+    },
+    /// Move a profile stored under the message's origin chain ID to its signer;
+    /// carries the origin chain's own copy, if it has one
+    MigrateProfile {
+        /// Profile the origin chain stored under its chain ID
+        profile: Option<PlayerProfile>,
+    },
+}
+
 impl ContractAbi for PlayerProfileAbi {
     type Operation = Operation;
     type Response = OperationResult;
@@ -31,8 +87,150 @@ impl ServiceAbi for PlayerProfileAbi {
     type QueryResponse = Response;
 }
 
+/// Default minimum time between two name changes (7 days, in ms)
+pub const DEFAULT_NAME_CHANGE_COOLDOWN_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+
+/// Name of the event stream level-ups are emitted on
+pub const LEVEL_UP_STREAM: &[u8] = b"level_ups";
+
+/// Settings fixed when the application is created
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileParameters {
+    /// XP thresholds for each level
+    #[serde(default)]
+    pub level_curve: LevelCurve,
+    /// Minimum time between two name changes (ms)
+    #[serde(default = "default_name_change_cooldown_ms")]
+    pub name_change_cooldown_ms: u64,
+}
+
+impl Default for ProfileParameters {
+    fn default() -> Self {
+        ProfileParameters {
+            level_curve: LevelCurve::default(),
+            name_change_cooldown_ms: DEFAULT_NAME_CHANGE_COOLDOWN_MS,
+        }
+    }
+}
+
+fn default_name_change_cooldown_ms() -> u64 {
+    DEFAULT_NAME_CHANGE_COOLDOWN_MS
+}
+
+/// XP thresholds for each level, part of the application parameters
+///
+/// Every profile starts at level 1; reaching `thresholds[i]` XP grants level `i + 2`.
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct LevelCurve {
+    /// XP needed for each level above 1, strictly ascending
+    pub thresholds: Vec<u64>,
+}
+
+impl Default for LevelCurve {
+    fn default() -> Self {
+        LevelCurve {
+            thresholds: vec![100, 250, 500, 1_000, 2_000, 4_000, 8_000, 16_000, 32_000],
+        }
+    }
+}
+
+impl LevelCurve {
+    /// Check that thresholds are strictly ascending
+    pub fn is_valid(&self) -> bool {
+        self.thresholds.windows(2).all(|pair| pair[0] < pair[1])
+    }
+    
+    /// Highest level reachable on this curve
+    pub fn max_level(&self) -> u32 {
+        self.thresholds.len() as u32 + 1
+    }
+    
+    /// Level reached with the given XP
+    pub fn level_for(&self, xp: u64) -> u32 {
+        self.thresholds.iter().take_while(|&&threshold| xp >= threshold).count() as u32 + 1
+    }
+    
+    /// XP still missing for the next level (None at the maximum level)
+    pub fn xp_to_next_level(&self, xp: u64) -> Option<u64> {
+        let next = self.thresholds.get(self.level_for(xp) as usize - 1)?;
+        Some(next - xp)
+    }
+    
+    /// Progress from the current level to the next, in percent (100 at the maximum level)
+    pub fn progress(&self, xp: u64) -> u32 {
+        let level = self.level_for(xp) as usize;
+        let floor = if level >= 2 { self.thresholds[level - 2] } else { 0 };
+        match self.thresholds.get(level - 1) {
+            Some(&next) => ((xp - floor) * 100 / (next - floor)) as u32,
+            None => 100,
+        }
+    }
+}
+
+/// Events emitted by the contract
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProfileEvent {
+    /// A profile's XP crossed one or more level thresholds
+    LevelUp {
+        /// Profile owner address
+        owner: String,
+        /// Level before the update
+        old_level: u32,
+        /// Level after the update
+        new_level: u32,
+        /// XP after the update
+        xp: u64,
+        /// Game whose report caused the level-up (None for admin corrections)
+        game: Option<ApplicationId>,
+        /// Timestamp of the level-up (Unix ms)
+        timestamp: u64,
+    },
+}
+
+/// Maximum length of a profile bio (characters)
+pub const MAX_BIO_LENGTH: usize = 160;
+
+/// Maximum number of social links on a profile
+pub const MAX_SOCIAL_LINKS: usize = 5;
+
+/// A profile picture; exactly one source is set
+#[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject, InputObject, PartialEq, Eq)]
+#[graphql(input_name = "AvatarInput")]
+pub struct Avatar {
+    /// Image URL (https:// or ipfs://)
+    pub url: Option<String>,
+    /// Hex content hash of the image
+    pub content_hash: Option<String>,
+    /// Meme the image URL belongs to; only set by the game the meme is owned in
+    #[graphql(skip_input)]
+    #[serde(default)]
+    pub meme: Option<MemeAvatar>,
+}
+
+/// A meme used as avatar, checked against its owner by the game holding it
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct MemeAvatar {
+    /// Application ID of the game the meme is owned in
+    pub game: ApplicationId,
+    /// ID of the meme in that game
+    pub meme_id: u64,
+}
+
+/// A link to one of the player's social accounts
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, InputObject, PartialEq, Eq)]
+#[graphql(input_name = "SocialLinkInput")]
+pub struct SocialLink {
+    /// Platform name (e.g., "twitter", "github")
+    pub platform: String,
+    /// Profile URL (https://)
+    pub url: String,
+}
+
 /// A player profile stored on-chain
 #[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+#[graphql(complex)]
 pub struct PlayerProfile {
     /// The player's display name
     pub name: String,
@@ -44,6 +242,229 @@ pub struct PlayerProfile {
     pub games_played: u64,
     /// Total wins
     pub wins: u64,
+    /// Timestamp of the last name change (Unix ms)
+    pub name_changed_at: Option<u64>,
+    /// Profile picture
+    pub avatar: Option<Avatar>,
+    /// Short self-description
+    pub bio: String,
+    /// Country or region code (ISO 3166-1 alpha-2, e.g., "DE")
+    pub region: Option<String>,
+    /// Social links
+    pub links: Vec<SocialLink>,
+    /// Whether stats are hidden from profile listings and leaderboards
+    pub private: bool,
+    /// IDs of badges pinned to the profile, in display order
+    pub showcase: Vec<String>,
+}
+
+impl PlayerProfile {
+    /// The profile as shown to other players: stats are zeroed for private profiles
+    pub fn public_view(&self) -> PlayerProfile {
+        if !self.private {
+            return self.clone();
+        }
+        PlayerProfile {
+            xp: 0,
+            games_played: 0,
+            wins: 0,
+            ..self.clone()
+        }
+    }
+}
+
+/// Maximum number of players in each relationship list a player grows by
+/// their own actions (following, friends, sent requests and blocks)
+pub const MAX_RELATIONSHIPS: usize = 1000;
+
+/// How one player relates to another, stored for each side of a pair
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Relation {
+    /// This player follows the other
+    pub following: bool,
+    /// The other player follows this player
+    pub follower: bool,
+    /// The players are friends
+    pub friend: bool,
+    /// The other player sent a friend request
+    pub incoming_request: bool,
+    /// This player sent a friend request
+    pub outgoing_request: bool,
+    /// This player blocked the other
+    pub blocked: bool,
+    /// The other player blocked this player
+    pub blocked_by: bool,
+}
+
+impl Relation {
+    /// Whether the players are not related at all
+    pub fn is_empty(&self) -> bool {
+        *self == Relation::default()
+    }
+    
+    /// Drop follows, friendship and requests, keeping blocks (used when blocking)
+    pub fn sever(&mut self) {
+        self.following = false;
+        self.follower = false;
+        self.friend = false;
+        self.incoming_request = false;
+        self.outgoing_request = false;
+    }
+}
+
+/// Number of players in each of a player's relationship lists
+#[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct RelationshipCounts {
+    /// Players this player follows
+    pub following: u32,
+    /// Players following this player
+    pub followers: u32,
+    /// Mutual friends
+    pub friends: u32,
+    /// Pending friend requests received
+    pub incoming_requests: u32,
+    /// Pending friend requests sent
+    pub outgoing_requests: u32,
+    /// Players this player has blocked
+    pub blocked: u32,
+}
+
+impl RelationshipCounts {
+    /// Account for a relation with one player changing from `old` to `new`
+    pub fn apply(&mut self, old: &Relation, new: &Relation) {
+        fn adjust(count: &mut u32, was: bool, is: bool) {
+            match (was, is) {
+                (false, true) => *count += 1,
+                (true, false) => *count = count.saturating_sub(1),
+                _ => {}
+            }
+        }
+        adjust(&mut self.following, old.following, new.following);
+        adjust(&mut self.followers, old.follower, new.follower);
+        adjust(&mut self.friends, old.friend, new.friend);
+        adjust(&mut self.incoming_requests, old.incoming_request, new.incoming_request);
+        adjust(&mut self.outgoing_requests, old.outgoing_request, new.outgoing_request);
+        adjust(&mut self.blocked, old.blocked, new.blocked);
+    }
+    
+    /// Size of the longest list the player grows by their own actions
+    pub fn longest_own_list(&self) -> usize {
+        [self.following, self.friends, self.outgoing_requests, self.blocked]
+            .into_iter()
+            .max()
+            .unwrap_or(0) as usize
+    }
+}
+
+/// A player's relationships with other players, by owner address
+#[derive(Clone, Debug, Default, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct Relationships {
+    /// Players this player follows
+    pub following: Vec<String>,
+    /// Players following this player
+    pub followers: Vec<String>,
+    /// Mutual friends
+    pub friends: Vec<String>,
+    /// Pending friend requests received
+    pub incoming_requests: Vec<String>,
+    /// Pending friend requests sent
+    pub outgoing_requests: Vec<String>,
+    /// Players this player has blocked
+    pub blocked: Vec<String>,
+    /// Players who have blocked this player (kept private)
+    #[graphql(skip)]
+    pub blocked_by: Vec<String>,
+}
+
+impl Relationships {
+    /// Add a player to every list their relation puts them in
+    pub fn insert(&mut self, player: &str, relation: &Relation) {
+        for (list, member) in [
+            (&mut self.following, relation.following),
+            (&mut self.followers, relation.follower),
+            (&mut self.friends, relation.friend),
+            (&mut self.incoming_requests, relation.incoming_request),
+            (&mut self.outgoing_requests, relation.outgoing_request),
+            (&mut self.blocked, relation.blocked),
+            (&mut self.blocked_by, relation.blocked_by),
+        ] {
+            if member {
+                list.push(player.to_string());
+            }
+        }
+    }
+    
+    /// Whether this player has blocked the given player
+    pub fn has_blocked(&self, player: &str) -> bool {
+        self.blocked.iter().any(|p| p == player)
+    }
+}
+
+/// Everything stored about a profile, as exported for the player
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct ProfileBundle {
+    /// Canonical owner address
+    pub owner: String,
+    /// The profile itself
+    pub profile: PlayerProfile,
+    /// Past name changes, oldest first
+    pub name_history: Vec<NameChange>,
+    /// Stats contributed by each game (empty for private profiles)
+    pub game_stats: Vec<GameStats>,
+    /// Follows, followers and friends; requests and blocks are left out
+    pub relationships: Relationships,
+    /// Account owners and chains linked to the profile
+    pub linked_identities: Vec<String>,
+    /// Badges awarded to the profile, oldest first
+    pub badges: Vec<AwardedBadge>,
+}
+
+/// A profile export in both structured and BCS form
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject)]
+pub struct ProfileExport {
+    /// The exported data
+    pub bundle: ProfileBundle,
+    /// Hex-encoded BCS serialization of `bundle`
+    pub bcs_hex: String,
+}
+
+/// Level fields computed from the level curve the service puts in the schema data;
+/// private profiles hide them along with their stats
+#[ComplexObject]
+impl PlayerProfile {
+    /// Current level (null for private profiles)
+    async fn level(&self, ctx: &Context<'_>) -> Option<u32> {
+        (!self.private).then(|| level_curve(ctx).level_for(self.xp))
+    }
+    
+    /// XP still missing for the next level (null at the maximum level and for private profiles)
+    async fn xp_to_next_level(&self, ctx: &Context<'_>) -> Option<u64> {
+        if self.private {
+            return None;
+        }
+        level_curve(ctx).xp_to_next_level(self.xp)
+    }
+    
+    /// Progress towards the next level, in percent (null for private profiles)
+    async fn progress(&self, ctx: &Context<'_>) -> Option<u32> {
+        (!self.private).then(|| level_curve(ctx).progress(self.xp))
+    }
+}
+
+/// Get the level curve from the schema data, falling back to the default curve
+fn level_curve(ctx: &Context<'_>) -> LevelCurve {
+    ctx.data_opt::<LevelCurve>().cloned().unwrap_or_default()
+}
+
+/// A previous name of a profile
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct NameChange {
+    /// The name given up
+    pub old_name: String,
+    /// The name taken instead
+    pub new_name: String,
+    /// Timestamp of the change (Unix ms)
+    pub changed_at: u64,
 }
 
 impl std::fmt::Display for PlayerProfile {
@@ -56,6 +477,170 @@ impl std::fmt::Display for PlayerProfile {
     }
 }
 
+/// A game application allowed to update player stats
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct RegisteredGame {
+    /// Application ID of the game contract
+    pub application_id: ApplicationId,
+    /// Display name (e.g., "Typing Arena")
+    pub name: String,
+    /// Timestamp when the game was registered (Unix ms)
+    pub registered_at: u64,
+}
+
+/// Stats a single game has contributed to a profile
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct GameStats {
+    /// Application ID of the game contract
+    pub game: ApplicationId,
+    /// Experience points earned in this game
+    pub xp: u64,
+    /// Games played in this game
+    pub games_played: u64,
+    /// Wins in this game
+    pub wins: u64,
+    /// Timestamp of the last update from this game (Unix ms)
+    pub updated_at: u64,
+}
+
+impl GameStats {
+    /// Empty stats for a game
+    pub fn new(game: ApplicationId) -> Self {
+        GameStats {
+            game,
+            xp: 0,
+            games_played: 0,
+            wins: 0,
+            updated_at: 0,
+        }
+    }
+    
+    /// Get the value of one stat
+    pub fn value(&self, stat: GameStat) -> u64 {
+        match stat {
+            GameStat::Xp => self.xp,
+            GameStat::GamesPlayed => self.games_played,
+            GameStat::Wins => self.wins,
+        }
+    }
+}
+
+/// A stat tracked per game, used to sort per-game leaderboards
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum GameStat {
+    /// Experience points
+    Xp,
+    /// Games played
+    GamesPlayed,
+    /// Wins
+    Wins,
+}
+
+impl GameStat {
+    /// Every stat, each with its own per-game ranking index
+    pub const ALL: [GameStat; 3] = [GameStat::Xp, GameStat::GamesPlayed, GameStat::Wins];
+    
+    /// Byte identifying this stat in per-game ranking keys
+    pub fn tag(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A key profile listings and leaderboards can be ordered by (best first)
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum ProfileSort {
+    /// Most XP
+    #[default]
+    Xp,
+    /// Most wins
+    Wins,
+    /// Most games played
+    GamesPlayed,
+    /// Highest share of games won
+    WinRate,
+    /// Most recently created
+    Newest,
+}
+
+impl ProfileSort {
+    /// Every sort key, each with its own ranking index
+    pub const ALL: [ProfileSort; 5] = [
+        ProfileSort::Xp,
+        ProfileSort::Wins,
+        ProfileSort::GamesPlayed,
+        ProfileSort::WinRate,
+        ProfileSort::Newest,
+    ];
+    
+    /// Prefix byte of this sort key's ranking index
+    pub fn tag(self) -> u8 {
+        self as u8
+    }
+    
+    /// Value a profile is ranked by; win rate is in basis points
+    pub fn score(self, profile: &PlayerProfile) -> u64 {
+        match self {
+            ProfileSort::Xp => profile.xp,
+            ProfileSort::Wins => profile.wins,
+            ProfileSort::GamesPlayed => profile.games_played,
+            ProfileSort::WinRate => {
+                if profile.games_played == 0 {
+                    0
+                } else {
+                    let wins = profile.wins.min(profile.games_played) as u128;
+                    (wins * 10_000 / profile.games_played as u128) as u64
+                }
+            }
+            ProfileSort::Newest => profile.created_at,
+        }
+    }
+}
+
+/// Maximum number of badges pinned to a profile's showcase
+pub const MAX_SHOWCASE_BADGES: usize = 3;
+
+/// How hard a badge is meant to be to earn
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, async_graphql::Enum)]
+pub enum BadgeRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+/// A badge in the catalog
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct Badge {
+    /// Unique ID (lowercase letters, digits, '-' and '_', up to 32 characters)
+    pub id: String,
+    /// Display title
+    pub title: String,
+    /// Icon URL (https:// or ipfs://)
+    pub icon_url: String,
+    /// Intended rarity
+    pub rarity: BadgeRarity,
+    /// How the badge is earned, as shown to players
+    pub criteria: String,
+    /// Games allowed to award the badge
+    pub games: Vec<ApplicationId>,
+    /// Timestamp when the badge was defined (Unix ms)
+    pub created_at: u64,
+    /// Number of profiles holding the badge
+    pub holders: u64,
+}
+
+/// A badge awarded to a profile
+#[derive(Clone, Debug, Serialize, Deserialize, SimpleObject, PartialEq, Eq)]
+pub struct AwardedBadge {
+    /// ID of the badge in the catalog
+    pub badge_id: String,
+    /// Game that awarded the badge
+    pub game: ApplicationId,
+    /// Timestamp of the award (Unix ms)
+    pub awarded_at: u64,
+}
+
 /// Operations that can be executed by the contract
 #[derive(Debug, Clone, Serialize, Deserialize, GraphQLMutationRoot)]
 pub enum Operation {
@@ -64,7 +649,10 @@ pub enum Operation {
         /// The display name (3-20 characters)
         name: String,
     },
-    /// Update profile stats (called by game contracts)
+    /// Update profile stats
+    ///
+    /// Only accepted as an authenticated cross-application call from a
+    /// registered game; the deltas are attributed to that game.
     UpdateStats {
         /// XP to add
         #[serde(default)]
@@ -81,6 +669,203 @@ pub enum Operation {
         /// New display name
         name: String,
     },
+    /// Update the player's profile details; omitted fields are left unchanged
+    UpdateDetails {
+        /// New avatar (an avatar with no source set removes it)
+        avatar: Option<Avatar>,
+        /// New bio (empty to clear)
+        bio: Option<String>,
+        /// New region code (empty to clear)
+        region: Option<String>,
+        /// New social links, replacing the current ones
+        links: Option<Vec<SocialLink>>,
+        /// Hide stats from profile listings and leaderboards
+        private: Option<bool>,
+    },
+    /// Use a meme the signer owns as avatar
+    ///
+    /// Only accepted as an authenticated cross-application call from a
+    /// registered game, which checks that the signer owns the meme.
+    SetMemeAvatar {
+        /// ID of the meme in the calling game
+        meme_id: u64,
+        /// Image URL of the meme (https:// or ipfs://)
+        image_url: String,
+    },
+    /// Follow another player
+    Follow {
+        /// Owner address of the player to follow
+        player: String,
+    },
+    /// Stop following a player
+    Unfollow {
+        /// Owner address of the player to unfollow
+        player: String,
+    },
+    /// Ask a player to become friends (accepts their request if they already sent one)
+    SendFriendRequest {
+        /// Owner address of the player
+        player: String,
+    },
+    /// Withdraw a friend request sent to a player
+    CancelFriendRequest {
+        /// Owner address of the player
+        player: String,
+    },
+    /// Accept a friend request received from a player
+    AcceptFriendRequest {
+        /// Owner address of the player
+        player: String,
+    },
+    /// Decline a friend request received from a player
+    DeclineFriendRequest {
+        /// Owner address of the player
+        player: String,
+    },
+    /// End a friendship
+    RemoveFriend {
+        /// Owner address of the friend
+        player: String,
+    },
+    /// Block a player, dropping follows, friendship and requests both ways
+    Block {
+        /// Owner address of the player to block
+        player: String,
+    },
+    /// Unblock a player
+    Unblock {
+        /// Owner address of the player to unblock
+        player: String,
+    },
+    /// Check whether either player has blocked the other
+    ///
+    /// Only answered on the application's creator chain, which holds the
+    /// social graph; other chains get `NotCreatorChain`. Changes nothing.
+    CheckBlocked {
+        /// Owner address of the first player
+        player_a: String,
+        /// Owner address of the second player
+        player_b: String,
+    },
+    /// Ask to link another account owner or chain to the caller's profile;
+    /// the link takes effect once confirmed by that identity
+    RequestLink {
+        /// Account owner or chain ID to link
+        identity: String,
+    },
+    /// Confirm a link requested by a profile owner, as the signer or from
+    /// the chain being linked
+    ConfirmLink {
+        /// Canonical owner of the profile that requested the link
+        profile_owner: String,
+    },
+    /// Move the profile stored under the ID of the chain this is submitted
+    /// on, from when profiles were keyed by chain, to the signer
+    MigrateChainProfile,
+    /// Remove a linked account owner or chain from the caller's profile
+    Unlink {
+        /// Account owner or chain ID to unlink
+        identity: String,
+    },
+    /// Delete the caller's profile with its name, stats, relationships and links
+    DeleteProfile,
+    /// Start moving the caller's profile to a new account owner; the new
+    /// owner must accept with `AcceptProfileTransfer`
+    TransferProfile {
+        /// Account owner that will own the profile
+        new_owner: String,
+    },
+    /// Withdraw the caller's pending profile transfer
+    CancelProfileTransfer,
+    /// Take over a profile whose owner started a transfer to the signer
+    AcceptProfileTransfer {
+        /// Current owner address of the profile
+        from: String,
+    },
+    /// Block a name from being registered (admin only)
+    ReserveName {
+        /// Name to reserve
+        name: String,
+    },
+    /// Make a reserved name available again (admin only)
+    ReleaseName {
+        /// Name to release
+        name: String,
+    },
+    /// Hand the admin role to another account owner (admin only)
+    TransferAdmin {
+        /// Owner address of the new admin
+        new_admin: String,
+    },
+    /// Allow a game application to update stats (admin only)
+    RegisterGame {
+        /// Application ID of the game contract
+        application_id: ApplicationId,
+        /// Display name
+        name: String,
+    },
+    /// Remove a game application from the registry (admin only)
+    UnregisterGame {
+        /// Application ID of the game contract
+        application_id: ApplicationId,
+    },
+    /// Overwrite a player's stats for one game, adjusting the profile
+    /// totals by the difference (admin only, for corrections and backfills)
+    SetGameStats {
+        /// Profile owner address
+        owner: String,
+        /// Application ID of the game contract
+        game: ApplicationId,
+        /// Experience points earned in this game
+        xp: u64,
+        /// Games played in this game
+        games_played: u64,
+        /// Wins in this game
+        wins: u64,
+    },
+    /// Index up to `limit` profiles, in storage order after `after`, into the
+    /// leaderboard rankings (admin only, for profiles created before them)
+    BackfillRankings {
+        /// Owner address of the last profile of the previous batch (None to start)
+        after: Option<String>,
+        /// Maximum number of profiles to index
+        limit: u32,
+    },
+    /// Add a badge to the catalog or update its details (admin only)
+    DefineBadge {
+        /// Unique badge ID
+        id: String,
+        /// Display title
+        title: String,
+        /// Icon URL (https:// or ipfs://)
+        icon_url: String,
+        /// Intended rarity
+        rarity: BadgeRarity,
+        /// How the badge is earned
+        criteria: String,
+        /// Registered games allowed to award the badge (at least one)
+        games: Vec<ApplicationId>,
+    },
+    /// Award a badge to the signer's profile
+    ///
+    /// Only accepted as an authenticated cross-application call from a
+    /// registered game the badge lists.
+    AwardBadge {
+        /// ID of the badge in the catalog
+        badge_id: String,
+    },
+    /// Take a badge back from a profile (admin only)
+    RevokeBadge {
+        /// Profile owner address
+        owner: String,
+        /// ID of the badge in the catalog
+        badge_id: String,
+    },
+    /// Pin up to `MAX_SHOWCASE_BADGES` owned badges to the caller's profile
+    SetShowcase {
+        /// Badge IDs in display order (empty to clear)
+        badge_ids: Vec<String>,
+    },
 }
 
 /// Result of an operation
@@ -91,7 +876,31 @@ pub enum OperationResult {
     /// Profile was updated successfully
     ProfileUpdated,
     /// Stats were updated successfully
-    StatsUpdated { new_xp: u64, new_games: u64, new_wins: u64 },
+    StatsUpdated { new_xp: u64, new_games: u64, new_wins: u64, new_level: u32 },
+    /// Reserved names were updated successfully
+    ReservedNamesUpdated,
+    /// Game registry was updated successfully
+    GamesUpdated,
+    /// Follows, friendships or blocks were updated successfully
+    RelationshipsUpdated,
+    /// Whether either of two players has blocked the other
+    BlockStatus { blocked: bool },
+    /// Identity links were updated successfully
+    LinksUpdated,
+    /// Operation was forwarded to the application's creator chain
+    OperationForwarded,
+    /// Admin role was handed over successfully
+    AdminTransferred { new_admin: String },
+    /// Profile was deleted successfully
+    ProfileDeleted,
+    /// Profile now belongs to a new owner
+    ProfileTransferred { new_owner: String },
+    /// Rankings were backfilled; `next` is the `after` of the next batch, if any
+    RankingsBackfilled { next: Option<String> },
+    /// Badge catalog or showcase was updated successfully
+    BadgesUpdated,
+    /// Badge was awarded to the profile
+    BadgeAwarded { badge_id: String },
     /// Operation failed
     Error(String),
 }
@@ -108,9 +917,280 @@ pub enum ProfileError {
     #[error("Invalid name: {0}")]
     InvalidName(String),
     
-    #[error("Unauthorized: only the owner can modify this profile")]
+    #[error("Invalid avatar: {0}")]
+    InvalidAvatar(String),
+    
+    #[error("Invalid bio: {0}")]
+    InvalidBio(String),
+    
+    #[error("Invalid region: {0}")]
+    InvalidRegion(String),
+    
+    #[error("Invalid link: {0}")]
+    InvalidLink(String),
+    
+    #[error("Name is already taken")]
+    NameTaken,
+    
+    #[error("Name is reserved")]
+    NameReserved,
+    
+    #[error("Name was changed recently, try again in {0} seconds")]
+    NameChangeCooldown(u64),
+    
+    #[error("Operation must be signed by an account owner")]
+    Unauthenticated,
+    
+    #[error("Identity is already linked to a profile")]
+    AlreadyLinked,
+    
+    #[error("Identity has its own profile")]
+    IdentityHasProfile,
+    
+    #[error("Identity already has a pending link request from another profile")]
+    LinkRequestedByOther,
+    
+    #[error("No pending link request for this identity")]
+    LinkNotRequested,
+    
+    #[error("Identity is not linked to this profile")]
+    NotLinked,
+    
+    #[error("No pending profile transfer to this owner")]
+    TransferNotRequested,
+    
+    #[error("Cannot target your own profile")]
+    CannotTargetSelf,
+    
+    #[error("One of the players has blocked the other")]
+    Blocked,
+    
+    #[error("Already following this player")]
+    AlreadyFollowing,
+    
+    #[error("Not following this player")]
+    NotFollowing,
+    
+    #[error("Already friends with this player")]
+    AlreadyFriends,
+    
+    #[error("Not friends with this player")]
+    NotFriends,
+    
+    #[error("Friend request already sent")]
+    RequestAlreadySent,
+    
+    #[error("No pending friend request")]
+    NoFriendRequest,
+    
+    #[error("Player is already blocked")]
+    AlreadyBlocked,
+    
+    #[error("Player is not blocked")]
+    NotBlocked,
+    
+    #[error("Block checks are only answered on the application's creator chain")]
+    NotCreatorChain,
+    
+    #[error("Too many relationships (limit {0})")]
+    TooManyRelationships(usize),
+    
+    #[error("Invalid badge: {0}")]
+    InvalidBadge(String),
+    
+    #[error("Badge not found")]
+    BadgeNotFound,
+    
+    #[error("Badge already awarded to this profile")]
+    BadgeAlreadyAwarded,
+    
+    #[error("This game may not award the badge")]
+    BadgeGameNotAllowed,
+    
+    #[error("Badge has not been awarded to this profile")]
+    BadgeNotOwned,
+    
+    #[error("Too many showcase badges (limit {0})")]
+    TooManyShowcaseBadges(usize),
+    
+    #[error("Admin must be an account owner")]
+    InvalidAdmin,
+    
+    #[error("Unauthorized: only the admin can manage reserved names, games and badges")]
+    AdminOnly,
+    
+    #[error("Unauthorized: stats and badges can only be updated by registered game applications")]
     Unauthorized,
     
     #[error("State error: {0}")]
     StateError(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn default_level_curve_is_valid() {
+        let curve = LevelCurve::default();
+        assert!(curve.is_valid());
+        assert_eq!(curve.max_level(), 10);
+    }
+    
+    #[test]
+    fn level_curve_rejects_unordered_thresholds() {
+        assert!(!LevelCurve { thresholds: vec![100, 100] }.is_valid());
+        assert!(!LevelCurve { thresholds: vec![200, 100] }.is_valid());
+        assert!(LevelCurve { thresholds: vec![] }.is_valid());
+    }
+    
+    #[test]
+    fn parameters_reject_unknown_fields() {
+        use linera_sdk::serde_json;
+        
+        let parameters: ProfileParameters = serde_json::from_str(
+            r#"{"level_curve":{"thresholds":[100,250]},"name_change_cooldown_ms":604800000}"#,
+        )
+        .unwrap();
+        assert_eq!(parameters.level_curve.thresholds, [100, 250]);
+        assert!(serde_json::from_str::<ProfileParameters>(r#"{"thresholds":[100,250]}"#).is_err());
+        assert!(serde_json::from_str::<ProfileParameters>(r#"{"level_curve":{"threshold":[100]}}"#).is_err());
+    }
+    
+    #[test]
+    fn level_for_counts_reached_thresholds() {
+        let curve = LevelCurve { thresholds: vec![100, 300] };
+        assert_eq!(curve.level_for(0), 1);
+        assert_eq!(curve.level_for(99), 1);
+        assert_eq!(curve.level_for(100), 2);
+        assert_eq!(curve.level_for(299), 2);
+        assert_eq!(curve.level_for(300), 3);
+        assert_eq!(curve.level_for(u64::MAX), 3);
+    }
+    
+    #[test]
+    fn xp_to_next_level_stops_at_max_level() {
+        let curve = LevelCurve { thresholds: vec![100, 300] };
+        assert_eq!(curve.xp_to_next_level(0), Some(100));
+        assert_eq!(curve.xp_to_next_level(150), Some(150));
+        assert_eq!(curve.xp_to_next_level(300), None);
+    }
+    
+    #[test]
+    fn progress_is_relative_to_current_level() {
+        let curve = LevelCurve { thresholds: vec![100, 300] };
+        assert_eq!(curve.progress(0), 0);
+        assert_eq!(curve.progress(50), 50);
+        assert_eq!(curve.progress(100), 0);
+        assert_eq!(curve.progress(200), 50);
+        assert_eq!(curve.progress(300), 100);
+        assert_eq!(LevelCurve { thresholds: vec![] }.progress(0), 100);
+    }
+    
+    #[test]
+    fn sever_keeps_blocks() {
+        let mut relation = Relation {
+            following: true,
+            follower: true,
+            friend: true,
+            incoming_request: true,
+            outgoing_request: true,
+            blocked: true,
+            blocked_by: true,
+        };
+        relation.sever();
+        assert_eq!(relation, Relation { blocked: true, blocked_by: true, ..Relation::default() });
+        assert!(!relation.is_empty());
+        
+        relation.blocked = false;
+        relation.blocked_by = false;
+        assert!(relation.is_empty());
+    }
+    
+    #[test]
+    fn counts_follow_relation_changes() {
+        let mut counts = RelationshipCounts::default();
+        let none = Relation::default();
+        let friends = Relation { following: true, follower: true, friend: true, ..Relation::default() };
+        let blocked = Relation { blocked: true, ..Relation::default() };
+        
+        counts.apply(&none, &friends);
+        assert_eq!(counts, RelationshipCounts { following: 1, followers: 1, friends: 1, ..RelationshipCounts::default() });
+        
+        counts.apply(&friends, &blocked);
+        assert_eq!(counts, RelationshipCounts { blocked: 1, ..RelationshipCounts::default() });
+        
+        // Removing what was never counted does not wrap around
+        counts.apply(&friends, &none);
+        assert_eq!(counts, RelationshipCounts { blocked: 1, ..RelationshipCounts::default() });
+    }
+    
+    #[test]
+    fn longest_own_list_ignores_lists_grown_by_others() {
+        let counts = RelationshipCounts {
+            following: 3,
+            followers: 50,
+            friends: 2,
+            incoming_requests: 40,
+            outgoing_requests: 4,
+            blocked: 1,
+        };
+        assert_eq!(counts.longest_own_list(), 4);
+    }
+    
+    #[test]
+    fn relationships_list_players_by_relation() {
+        let mut relationships = Relationships::default();
+        relationships.insert("alice", &Relation { following: true, friend: true, ..Relation::default() });
+        relationships.insert("bob", &Relation { incoming_request: true, ..Relation::default() });
+        relationships.insert("carol", &Relation { blocked: true, blocked_by: true, ..Relation::default() });
+        relationships.insert("dave", &Relation::default());
+        
+        assert_eq!(relationships.following, vec!["alice"]);
+        assert_eq!(relationships.friends, vec!["alice"]);
+        assert_eq!(relationships.incoming_requests, vec!["bob"]);
+        assert_eq!(relationships.blocked, vec!["carol"]);
+        assert_eq!(relationships.blocked_by, vec!["carol"]);
+        assert!(relationships.followers.is_empty());
+        assert!(relationships.has_blocked("carol"));
+        assert!(!relationships.has_blocked("bob"));
+    }
+    
+    #[test]
+    fn sort_scores_read_profile_fields() {
+        let profile = PlayerProfile {
+            xp: 1_500,
+            games_played: 8,
+            wins: 2,
+            created_at: 42,
+            ..PlayerProfile::default()
+        };
+        assert_eq!(ProfileSort::Xp.score(&profile), 1_500);
+        assert_eq!(ProfileSort::Wins.score(&profile), 2);
+        assert_eq!(ProfileSort::GamesPlayed.score(&profile), 8);
+        assert_eq!(ProfileSort::WinRate.score(&profile), 2_500);
+        assert_eq!(ProfileSort::Newest.score(&profile), 42);
+    }
+    
+    #[test]
+    fn win_rate_is_bounded() {
+        let unplayed = PlayerProfile { wins: 3, ..PlayerProfile::default() };
+        assert_eq!(ProfileSort::WinRate.score(&unplayed), 0);
+        
+        // Wins corrected above games played count as a perfect record
+        let corrected = PlayerProfile { wins: 9, games_played: 4, ..PlayerProfile::default() };
+        assert_eq!(ProfileSort::WinRate.score(&corrected), 10_000);
+        
+        let huge = PlayerProfile { wins: u64::MAX, games_played: u64::MAX, ..PlayerProfile::default() };
+        assert_eq!(ProfileSort::WinRate.score(&huge), 10_000);
+    }
+    
+    #[test]
+    fn sort_tags_are_distinct() {
+        for (i, a) in ProfileSort::ALL.iter().enumerate() {
+            for b in &ProfileSort::ALL[i + 1..] {
+                assert_ne!(a.tag(), b.tag());
+            }
+        }
+    }
+}
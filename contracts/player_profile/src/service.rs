@@ -6,8 +6,17 @@
 //! Service implementation for Player Profile application
 //! 
 //! This service exposes a GraphQL API for querying:
-//! - Individual profiles by owner
-//! - Leaderboard (top profiles by XP)
+//! - Individual profiles by owner or by name
+//! - Name availability, reservations and history
+//! - Registered games, per-game stat breakdowns and per-game leaderboards
+//! - Levels computed from XP with the application's level curve
+//! - Followers, following, friends, friend requests and blocks
+//! - Linked account owners and chains of a profile
+//! - Complete profile exports and pending profile transfers
+//! - Leaderboards by XP, wins, games played, win rate or age, with cursors and ranks
+//! - The badge catalog, badge holders and how rare each badge is
+//!
+//! Private profiles keep their stats and levels out of every query, exports included.
 
 mod state;
 
@@ -16,11 +25,11 @@ use std::sync::Arc;
 use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
 use linera_sdk::{
     graphql::GraphQLMutationRoot as _,
-    linera_base_types::WithServiceAbi,
+    linera_base_types::{ApplicationId, WithServiceAbi},
     views::View,
     Service, ServiceRuntime,
 };
-use player_profile::{Operation, PlayerProfile, PlayerProfileAbi};
+use player_profile::{AwardedBadge, Badge, GameStat, GameStats, LevelCurve, NameChange, Operation, PlayerProfile, PlayerProfileAbi, ProfileBundle, ProfileParameters, ProfileExport, ProfileSort, RegisteredGame, RelationshipCounts, Relationships};
 
 use self::state::PlayerProfileState;
 
@@ -38,7 +47,7 @@ impl WithServiceAbi for PlayerProfileService {
 }
 
 impl Service for PlayerProfileService {
-    type Parameters = ();
+    type Parameters = ProfileParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = PlayerProfileState::load(runtime.root_view_storage_context())
@@ -51,11 +60,14 @@ impl Service for PlayerProfileService {
     }
 
     async fn handle_query(&self, request: Request) -> Response {
+        let parameters = self.runtime.application_parameters();
         let schema = Schema::build(
             QueryRoot { state: self.state.clone() },
             Operation::mutation_root(self.runtime.clone()),
             EmptySubscription,
         )
+        .data(parameters.level_curve.clone())
+        .data(parameters)
         .finish();
         schema.execute(request).await
     }
@@ -68,55 +80,372 @@ struct QueryRoot {
 
 #[Object]
 impl QueryRoot {
-    /// Get a profile by owner address
+    /// Get a profile by owner address or any identity linked to it
     async fn profile(&self, owner: String) -> Option<PlayerProfile> {
-        self.state.profiles.get(&owner).await.ok().flatten()
+        let owner = self.state.resolve_owner(&owner).await;
+        let profile = self.state.profiles.get(&owner).await.ok().flatten()?;
+        Some(profile.public_view())
     }
     
-    /// Check if a profile exists for the given owner
+    /// Check if a profile exists for the given owner or linked identity
     async fn has_profile(&self, owner: String) -> bool {
+        let owner = self.state.resolve_owner(&owner).await;
         self.state.profiles.get(&owner).await.ok().flatten().is_some()
     }
     
-    /// Get all profiles (limited for efficiency)
-    async fn all_profiles(&self, limit: Option<u32>) -> Vec<ProfileWithOwner> {
-        let limit = limit.unwrap_or(100) as usize;
-        let mut profiles = Vec::new();
+    /// Map an account owner or chain ID to the canonical owner address of its profile
+    async fn canonical_owner(&self, identity: String) -> String {
+        self.state.resolve_owner(&identity).await
+    }
+    
+    /// Get the account owners and chains linked to a profile
+    async fn linked_identities(&self, owner: String) -> Vec<String> {
+        self.state.get_linked_identities(&owner).await
+    }
+    
+    /// Get the profile owner that asked to link an identity, if any
+    async fn pending_link(&self, identity: String) -> Option<String> {
+        self.state.pending_links.get(&identity).await.ok().flatten()
+    }
+    
+    /// Export everything stored about a profile, as structured data and BCS
+    /// (without stats for private profiles)
+    async fn export_profile(&self, owner: String) -> async_graphql::Result<Option<ProfileExport>> {
+        let owner = self.state.resolve_owner(&owner).await;
+        let Some(profile) = self.state.profiles.get(&owner).await? else {
+            return Ok(None);
+        };
         
-        // Iterate through all profiles
-        self.state.profiles.for_each_index_value(|owner, profile| {
-            if profiles.len() < limit {
-                profiles.push(ProfileWithOwner {
-                    owner: owner.clone(),
-                    profile: profile.into_owned(),
-                });
-            }
+        // Anyone can export a profile, so requests, blocks and the stats of
+        // private profiles stay out of it
+        let profile = profile.public_view();
+        let game_stats = if profile.private {
+            Vec::new()
+        } else {
+            self.state.get_game_stats(&owner).await
+        };
+        let relationships = self.state.get_relationships(&owner).await?;
+        let relationships = Relationships {
+            following: relationships.following,
+            followers: relationships.followers,
+            friends: relationships.friends,
+            ..Relationships::default()
+        };
+        
+        let bundle = ProfileBundle {
+            name_history: self.state.name_history.get(&owner).await.ok().flatten().unwrap_or_default(),
+            game_stats,
+            relationships,
+            linked_identities: self.state.get_linked_identities(&owner).await,
+            badges: self.state.get_player_badges(&owner).await,
+            owner,
+            profile,
+        };
+        let bcs_hex = bcs::to_bytes(&bundle)?
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        
+        Ok(Some(ProfileExport { bundle, bcs_hex }))
+    }
+    
+    /// Get the account owner a profile is being transferred to, if any
+    async fn pending_transfer(&self, owner: String) -> Option<String> {
+        self.state.pending_transfers.get(&owner).await.ok().flatten()
+    }
+    
+    /// Get a profile by name (case-insensitive)
+    async fn profile_by_name(&self, name: String) -> Option<ProfileWithOwner> {
+        let key = PlayerProfileState::normalize_name(&name);
+        let owner = self.state.name_index.get(&key).await.ok().flatten()?;
+        let profile = self.state.profiles.get(&owner).await.ok().flatten()?.public_view();
+        Some(ProfileWithOwner { owner, profile })
+    }
+    
+    /// Check if a name can be registered (not taken and not reserved)
+    async fn is_name_available(&self, name: String) -> bool {
+        let key = PlayerProfileState::normalize_name(&name);
+        let taken = self.state.name_index.contains_key(&key).await.unwrap_or(true);
+        let reserved = self.state.reserved_names.contains_key(&key).await.unwrap_or(true);
+        !taken && !reserved
+    }
+    
+    /// Get the past name changes of a profile, oldest first
+    async fn name_history(&self, owner: String) -> Vec<NameChange> {
+        self.state.name_history.get(&owner).await.ok().flatten().unwrap_or_default()
+    }
+    
+    /// Get all reserved names (normalized)
+    async fn reserved_names(&self) -> Vec<String> {
+        self.state.reserved_names.indices().await.unwrap_or_default()
+    }
+    
+    /// Get the game applications allowed to update stats
+    async fn registered_games(&self) -> Vec<RegisteredGame> {
+        let mut games = Vec::new();
+        self.state.registered_games.for_each_index_value(|_, game| {
+            games.push(game.into_owned());
             Ok(())
         }).await.ok();
+        games
+    }
+    
+    /// Check if a game application is allowed to update stats
+    async fn is_registered_game(&self, application_id: ApplicationId) -> bool {
+        self.state.registered_games.contains_key(&application_id).await.unwrap_or(false)
+    }
+    
+    /// Get the stats each game has contributed to a profile (empty for private profiles)
+    async fn game_stats(&self, owner: String) -> Vec<GameStats> {
+        match self.state.profiles.get(&owner).await.ok().flatten() {
+            Some(profile) if !profile.private => self.state.get_game_stats(&owner).await,
+            _ => Vec::new(),
+        }
+    }
+    
+    /// Get a profile with its stats broken down by game (no stats for private profiles)
+    async fn profile_breakdown(&self, owner: String) -> Option<ProfileBreakdown> {
+        let profile = self.state.profiles.get(&owner).await.ok().flatten()?.public_view();
+        let games = if profile.private {
+            Vec::new()
+        } else {
+            self.state.get_game_stats(&owner).await
+        };
         
-        profiles
+        // Stats recorded before per-game attribution belong to no game
+        let unattributed_xp = profile.xp.saturating_sub(games.iter().map(|g| g.xp).sum());
+        let unattributed_games = profile.games_played.saturating_sub(games.iter().map(|g| g.games_played).sum());
+        let unattributed_wins = profile.wins.saturating_sub(games.iter().map(|g| g.wins).sum());
+        
+        Some(ProfileBreakdown {
+            owner,
+            profile,
+            games,
+            unattributed_xp,
+            unattributed_games,
+            unattributed_wins,
+        })
     }
     
-    /// Get leaderboard sorted by XP (descending)
-    async fn leaderboard(&self, limit: Option<u32>) -> Vec<ProfileWithOwner> {
+    /// Get the leaderboard of a single game, sorted by the given stat (XP by
+    /// default), leaving out private profiles
+    async fn game_leaderboard(
+        &self,
+        game: ApplicationId,
+        sort_by: Option<GameStat>,
+        limit: Option<u32>,
+    ) -> Vec<GameLeaderboardEntry> {
+        let sort_by = sort_by.unwrap_or(GameStat::Xp);
+        let limit = limit.unwrap_or(10) as usize;
+        
+        // The index only holds public profiles, best first
+        let mut leaderboard = Vec::new();
+        for owner in self.state.ranked_game_owners(&game, sort_by, limit).await {
+            let stats = self.state.get_game_stat(&owner, &game).await.ok().flatten();
+            let profile = self.state.profiles.get(&owner).await.ok().flatten();
+            if let (Some(stats), Some(profile)) = (stats, profile) {
+                leaderboard.push(GameLeaderboardEntry { owner, name: profile.name, stats });
+            }
+        }
+        leaderboard
+    }
+    
+    /// Get all relationships of a player, including pending friend requests
+    async fn relationships(&self, owner: String) -> async_graphql::Result<Relationships> {
+        Ok(self.state.get_relationships(&owner).await?)
+    }
+    
+    /// Get the number of players in each of a player's relationship lists
+    async fn relationship_counts(&self, owner: String) -> RelationshipCounts {
+        self.state.get_relationship_counts(&owner).await.unwrap_or_default()
+    }
+    
+    /// Get the players following a player
+    async fn followers(&self, owner: String) -> async_graphql::Result<Vec<String>> {
+        Ok(self.state.get_relationships(&owner).await?.followers)
+    }
+    
+    /// Get the players a player follows
+    async fn following(&self, owner: String) -> async_graphql::Result<Vec<String>> {
+        Ok(self.state.get_relationships(&owner).await?.following)
+    }
+    
+    /// Get a player's friends
+    async fn friends(&self, owner: String) -> async_graphql::Result<Vec<String>> {
+        Ok(self.state.get_relationships(&owner).await?.friends)
+    }
+    
+    /// Get the friends two players have in common
+    async fn mutual_friends(&self, player_a: String, player_b: String) -> async_graphql::Result<Vec<String>> {
+        let friends_b = self.state.get_relationships(&player_b).await?.friends;
+        Ok(self.state.get_relationships(&player_a).await?.friends
+            .into_iter()
+            .filter(|friend| friends_b.contains(friend))
+            .collect())
+    }
+    
+    /// Check whether either player has blocked the other
+    async fn is_blocked(&self, player_a: String, player_b: String) -> async_graphql::Result<bool> {
+        Ok(self.state.is_blocked_between(&player_a, &player_b).await?)
+    }
+    
+    /// Get the leaderboard of a player and their friends sorted by XP
+    /// (descending), leaving out private friends
+    async fn friends_leaderboard(&self, owner: String, limit: Option<u32>) -> async_graphql::Result<Vec<ProfileWithOwner>> {
         let limit = limit.unwrap_or(10) as usize;
         let mut profiles = Vec::new();
         
-        // Collect all profiles
-        self.state.profiles.for_each_index_value(|owner, profile| {
-            profiles.push(ProfileWithOwner {
-                owner: owner.clone(),
-                profile: profile.into_owned(),
-            });
-            Ok(())
-        }).await.ok();
+        // The player always has their own entry
+        if let Some(profile) = self.state.profiles.get(&owner).await.ok().flatten() {
+            profiles.push(ProfileWithOwner { owner: owner.clone(), profile: profile.public_view() });
+        }
+        for friend in self.state.get_relationships(&owner).await?.friends {
+            if let Some(profile) = self.state.profiles.get(&friend).await.ok().flatten() {
+                if !profile.private {
+                    profiles.push(ProfileWithOwner { owner: friend, profile });
+                }
+            }
+        }
         
         // Sort by XP descending
-        profiles.sort_by(|a, b| b.profile.xp.cmp(&a.profile.xp));
+        profiles.sort_by_key(|entry| std::cmp::Reverse(entry.profile.xp));
         
         // Return top N
         profiles.truncate(limit);
-        profiles
+        Ok(profiles)
+    }
+    
+    /// Get a page of all profiles, starting after the owner given as cursor,
+    /// hiding the stats of private ones
+    async fn all_profiles(&self, after: Option<String>, limit: Option<u32>) -> async_graphql::Result<ProfilePage> {
+        let limit = limit.unwrap_or(100) as usize;
+        if let Some(after) = &after {
+            if !self.state.profiles.contains_key(after).await? {
+                return Err(async_graphql::Error::new("Invalid cursor"));
+            }
+        }
+        let mut skipping = after.is_some();
+        let mut entries = Vec::new();
+        
+        // Iterate through profiles in storage order until the page is full
+        self.state.profiles.for_each_index_value_while(|owner, profile| {
+            if entries.len() >= limit {
+                return Ok(false);
+            }
+            if skipping {
+                skipping = after.as_ref() != Some(&owner);
+                return Ok(true);
+            }
+            entries.push(ProfileWithOwner {
+                profile: profile.public_view(),
+                owner,
+            });
+            Ok(true)
+        }).await?;
+        
+        let next_cursor = if entries.len() == limit {
+            entries.last().map(|entry| entry.owner.clone())
+        } else {
+            None
+        };
+        Ok(ProfilePage { entries, next_cursor })
+    }
+    
+    /// Get the top public profiles by a sort key (XP by default)
+    async fn leaderboard(&self, sort_by: Option<ProfileSort>, limit: Option<u32>) -> async_graphql::Result<Vec<ProfileWithOwner>> {
+        let limit = limit.unwrap_or(10) as usize;
+        let owners = self.state.ranked_owners(sort_by.unwrap_or_default(), None, limit).await?
+            .unwrap_or_default();
+        Ok(self.load_profiles(owners).await)
+    }
+    
+    /// Get a page of the leaderboard for a sort key, starting after the given cursor
+    async fn leaderboard_page(
+        &self,
+        sort_by: Option<ProfileSort>,
+        after: Option<String>,
+        limit: Option<u32>,
+    ) -> async_graphql::Result<ProfilePage> {
+        let sort = sort_by.unwrap_or_default();
+        let limit = limit.unwrap_or(10) as usize;
+        let owners = self.state.ranked_owners(sort, after.as_deref(), limit).await?
+            .ok_or_else(|| async_graphql::Error::new("Invalid cursor"))?;
+        let entries = self.load_profiles(owners).await;
+        
+        let next_cursor = if entries.len() == limit {
+            entries.last()
+                .map(|entry| PlayerProfileState::encode_cursor(sort, &entry.profile, &entry.owner))
+        } else {
+            None
+        };
+        Ok(ProfilePage { entries, next_cursor })
+    }
+    
+    /// Get a public profile's 1-based leaderboard rank by a sort key (XP by default)
+    async fn rank_of(&self, owner: String, sort_by: Option<ProfileSort>) -> async_graphql::Result<Option<u32>> {
+        let owner = self.state.resolve_owner(&owner).await;
+        Ok(self.state.get_rank(&owner, sort_by.unwrap_or_default()).await?)
+    }
+    
+    /// Get the badge catalog with the share of profiles holding each badge
+    async fn badges(&self) -> Vec<BadgeRarityStats> {
+        let total = self.state.profiles.count().await.unwrap_or(0) as u64;
+        let mut badges = Vec::new();
+        self.state.badges.for_each_index_value(|_, badge| {
+            badges.push(BadgeRarityStats::new(badge.into_owned(), total));
+            Ok(())
+        }).await.ok();
+        badges
+    }
+    
+    /// Get a badge with the share of profiles holding it
+    async fn badge_rarity(&self, badge_id: String) -> Option<BadgeRarityStats> {
+        let total = self.state.profiles.count().await.unwrap_or(0) as u64;
+        let badge = self.state.badges.get(&badge_id).await.ok().flatten()?;
+        Some(BadgeRarityStats::new(badge, total))
+    }
+    
+    /// Get up to `limit` owners holding a badge, starting after the given owner
+    async fn badge_holders(&self, badge_id: String, after: Option<String>, limit: Option<u32>) -> Vec<String> {
+        let limit = limit.unwrap_or(100) as usize;
+        self.state.get_badge_holders(&badge_id, after.as_deref(), limit).await
+    }
+    
+    /// Get the badges awarded to a profile, oldest first
+    async fn player_badges(&self, owner: String) -> Vec<AwardedBadge> {
+        let owner = self.state.resolve_owner(&owner).await;
+        self.state.get_player_badges(&owner).await
+    }
+    
+    /// Get the badges pinned to a profile's showcase, in display order
+    async fn showcase(&self, owner: String) -> Vec<Badge> {
+        let owner = self.state.resolve_owner(&owner).await;
+        let showcase = match self.state.profiles.get(&owner).await.ok().flatten() {
+            Some(profile) => profile.showcase,
+            None => return Vec::new(),
+        };
+        let mut badges = Vec::new();
+        for badge_id in showcase {
+            if let Some(badge) = self.state.badges.get(&badge_id).await.ok().flatten() {
+                badges.push(badge);
+            }
+        }
+        badges
+    }
+    
+    /// Get the XP thresholds of each level
+    async fn level_curve(&self, ctx: &async_graphql::Context<'_>) -> LevelCurve {
+        ctx.data_unchecked::<LevelCurve>().clone()
+    }
+    
+    /// Get the minimum time between two name changes (ms)
+    async fn name_change_cooldown_ms(&self, ctx: &async_graphql::Context<'_>) -> u64 {
+        ctx.data_unchecked::<ProfileParameters>().name_change_cooldown_ms
+    }
+    
+    /// Get the owner address of the admin
+    async fn admin(&self) -> String {
+        self.state.admin.get().clone()
     }
     
     /// Get total number of registered profiles
@@ -125,9 +454,73 @@ impl QueryRoot {
     }
 }
 
+impl QueryRoot {
+    /// Load the profiles of the given owners, preserving order
+    async fn load_profiles(&self, owners: Vec<String>) -> Vec<ProfileWithOwner> {
+        let mut profiles = Vec::new();
+        for owner in owners {
+            if let Some(profile) = self.state.profiles.get(&owner).await.ok().flatten() {
+                profiles.push(ProfileWithOwner { owner, profile });
+            }
+        }
+        profiles
+    }
+}
+
 /// A profile with its owner address (for leaderboard display)
 #[derive(Clone, async_graphql::SimpleObject)]
 struct ProfileWithOwner {
     owner: String,
     profile: PlayerProfile,
 }
+
+/// One page of profiles
+#[derive(Clone, async_graphql::SimpleObject)]
+struct ProfilePage {
+    /// Profiles on this page, in order
+    entries: Vec<ProfileWithOwner>,
+    /// Cursor to pass as `after` for the next page (None on the last page)
+    next_cursor: Option<String>,
+}
+
+/// A profile with its stats split by game
+#[derive(Clone, async_graphql::SimpleObject)]
+struct ProfileBreakdown {
+    owner: String,
+    profile: PlayerProfile,
+    /// Stats contributed by each game
+    games: Vec<GameStats>,
+    /// XP not attributed to any game
+    unattributed_xp: u64,
+    /// Games played not attributed to any game
+    unattributed_games: u64,
+    /// Wins not attributed to any game
+    unattributed_wins: u64,
+}
+
+/// A badge with how many of all profiles hold it
+#[derive(Clone, async_graphql::SimpleObject)]
+struct BadgeRarityStats {
+    badge: Badge,
+    /// Percentage of profiles holding the badge (0-100)
+    holder_percent: f64,
+}
+
+impl BadgeRarityStats {
+    fn new(badge: Badge, total_profiles: u64) -> Self {
+        let holder_percent = if total_profiles == 0 {
+            0.0
+        } else {
+            badge.holders as f64 * 100.0 / total_profiles as f64
+        };
+        BadgeRarityStats { badge, holder_percent }
+    }
+}
+
+/// A player's stats in one game (for per-game leaderboard display)
+#[derive(Clone, async_graphql::SimpleObject)]
+struct GameLeaderboardEntry {
+    owner: String,
+    name: String,
+    stats: GameStats,
+}
@@ -3,16 +3,572 @@
 
 //! State definitions for the Player Profile application
 
-use linera_sdk::views::{linera_views, MapView, RootView, ViewStorageContext};
+use linera_sdk::{
+    linera_base_types::ApplicationId,
+    views::{linera_views, ByteMapView, MapView, RegisterView, RootView, ViewError, ViewStorageContext},
+};
 
-use player_profile::PlayerProfile;
+use player_profile::{AwardedBadge, Badge, GameStat, GameStats, NameChange, PlayerProfile, ProfileSort, RegisteredGame, Relation, RelationshipCounts, Relationships};
 
-/// The application state stored on-chain
+/// The application state, kept on the application's creator chain
 /// 
-/// This uses a MapView to store profiles keyed by owner address (String).
+/// This uses a MapView to store profiles keyed by owner address (String): the
+/// string form of the `AccountOwner` that registered the profile.
 #[derive(RootView, async_graphql::SimpleObject)]
 #[view(context = ViewStorageContext)]
 pub struct PlayerProfileState {
     /// Map from owner address to player profile
     pub profiles: MapView<String, PlayerProfile>,
+    /// Map from normalized (lowercase) name to owner address
+    pub name_index: MapView<String, String>,
+    /// Normalized names nobody may register, with when they were reserved (Unix ms)
+    pub reserved_names: MapView<String, u64>,
+    /// Map from owner address to past name changes, oldest first
+    pub name_history: MapView<String, Vec<NameChange>>,
+    /// Account owner allowed to manage reserved names, the game registry and badges
+    pub admin: RegisterView<String>,
+    /// Game applications allowed to update stats, keyed by application ID
+    pub registered_games: MapView<ApplicationId, RegisteredGame>,
+    /// Per-game stats keyed by "application_id:owner", see `PlayerProfileState::game_stats_key`
+    #[graphql(skip)]
+    pub game_stats: ByteMapView<GameStats>,
+    /// Games each owner has stats in, keyed by "owner:application_id"
+    #[graphql(skip)]
+    pub player_games: ByteMapView<ApplicationId>,
+    /// Per-game stats of public profiles ordered by every stat, see `PlayerProfileState::game_rank_key`
+    #[graphql(skip)]
+    pub game_rankings: ByteMapView<()>,
+    /// How each player relates to another, keyed by "owner:player"
+    #[graphql(skip)]
+    pub relations: ByteMapView<Relation>,
+    /// Map from owner address to the size of each relationship list
+    pub relationship_counts: MapView<String, RelationshipCounts>,
+    /// Map from linked account owner or chain ID to canonical owner address
+    pub identity_links: MapView<String, String>,
+    /// Map from canonical owner address to its linked identities
+    pub linked_identities: MapView<String, Vec<String>>,
+    /// Map from account owner or chain ID to the owner address that asked to link it
+    pub pending_links: MapView<String, String>,
+    /// Pending link requests by the profile that made them, keyed by "owner:identity"
+    #[graphql(skip)]
+    pub link_requests: ByteMapView<()>,
+    /// Map from owner address to the account owner its profile is being transferred to
+    pub pending_transfers: MapView<String, String>,
+    /// Public profiles ordered by every sort key, see `PlayerProfileState::rank_key`
+    #[graphql(skip)]
+    pub rankings: ByteMapView<()>,
+    /// Number of ranked profiles under each score prefix, see `PlayerProfileState::count_key`
+    #[graphql(skip)]
+    pub rank_counts: ByteMapView<u64>,
+    /// Badge catalog keyed by badge ID
+    pub badges: MapView<String, Badge>,
+    /// Map from owner address to awarded badges, oldest first
+    pub player_badges: MapView<String, Vec<AwardedBadge>>,
+    /// Holders of each badge, see `PlayerProfileState::holder_key`
+    #[graphql(skip)]
+    pub badge_holders: ByteMapView<()>,
+}
+
+impl PlayerProfileState {
+    /// Normalize a name for uniqueness checks
+    pub fn normalize_name(name: &str) -> String {
+        name.trim().to_lowercase()
+    }
+    
+    /// Make a per-game stats key from game and owner
+    pub fn game_stats_key(game: &ApplicationId, owner: &str) -> Vec<u8> {
+        format!("{}:{}", game, owner).into_bytes()
+    }
+    
+    /// Make a key of the games an owner has stats in
+    fn player_game_key(owner: &str, game: &ApplicationId) -> Vec<u8> {
+        format!("{}:{}", owner, game).into_bytes()
+    }
+    
+    /// The part of a per-game ranking key identifying the game and stat
+    fn game_rank_prefix(game: &ApplicationId, stat: GameStat) -> Vec<u8> {
+        let mut prefix = format!("{}:", game).into_bytes();
+        prefix.push(stat.tag());
+        prefix
+    }
+    
+    /// Make a per-game ranking key: the game, the stat, then the inverted
+    /// value big-endian and the owner, so keys sort by value descending
+    fn game_rank_key(stat: GameStat, stats: &GameStats, owner: &str) -> Vec<u8> {
+        let mut key = Self::game_rank_prefix(&stats.game, stat);
+        key.extend_from_slice(&Self::rank_suffix(stats.value(stat), owner));
+        key
+    }
+    
+    /// Get an owner's stats for one game
+    pub async fn get_game_stat(&self, owner: &str, game: &ApplicationId) -> Result<Option<GameStats>, ViewError> {
+        self.game_stats.get(&Self::game_stats_key(game, owner)).await
+    }
+    
+    /// Store an owner's stats for one game, ranking them unless the profile is private
+    pub async fn save_game_stats(&mut self, owner: &str, stats: GameStats, private: bool) -> Result<(), ViewError> {
+        let previous = self.get_game_stat(owner, &stats.game).await?;
+        let ranked = (!private).then_some(&stats);
+        self.update_game_rankings(owner, previous.as_ref().filter(|_| !private), ranked)?;
+        self.player_games.insert(Self::player_game_key(owner, &stats.game), stats.game);
+        self.game_stats.insert(Self::game_stats_key(&stats.game, owner), stats);
+        Ok(())
+    }
+    
+    /// Remove an owner's stats for one game with their ranking entries
+    pub fn remove_game_stats(&mut self, owner: &str, stats: &GameStats, private: bool) -> Result<(), ViewError> {
+        self.update_game_rankings(owner, (!private).then_some(stats), None)?;
+        self.player_games.remove(Self::player_game_key(owner, &stats.game));
+        self.game_stats.remove(Self::game_stats_key(&stats.game, owner));
+        Ok(())
+    }
+    
+    /// Move an owner's per-game ranking entries from old to new stats;
+    /// `None` means no entry (no stats, or a private profile)
+    pub fn update_game_rankings(
+        &mut self,
+        owner: &str,
+        old: Option<&GameStats>,
+        new: Option<&GameStats>,
+    ) -> Result<(), ViewError> {
+        for stat in GameStat::ALL {
+            if let Some(old) = old {
+                self.game_rankings.remove(Self::game_rank_key(stat, old, owner));
+            }
+            if let Some(new) = new {
+                self.game_rankings.insert(Self::game_rank_key(stat, new, owner), ());
+            }
+        }
+        Ok(())
+    }
+    
+    /// Get the owners of up to `limit` public profiles with stats in a game,
+    /// best first by the given stat
+    pub async fn ranked_game_owners(&self, game: &ApplicationId, stat: GameStat, limit: usize) -> Vec<String> {
+        let mut owners = Vec::new();
+        self.game_rankings.for_each_key_while(|suffix| {
+            if owners.len() >= limit {
+                return Ok::<_, ViewError>(false);
+            }
+            if let Ok(owner) = std::str::from_utf8(&suffix[8..]) {
+                owners.push(owner.to_string());
+            }
+            Ok(true)
+        }, Self::game_rank_prefix(game, stat)).await.ok();
+        owners
+    }
+    
+    /// Make a ranking index key. The score is inverted and stored big-endian
+    /// so keys sort by sort key, then by score descending, then by owner.
+    fn rank_key(sort: ProfileSort, profile: &PlayerProfile, owner: &str) -> Vec<u8> {
+        let mut key = vec![sort.tag()];
+        key.extend_from_slice(&Self::rank_suffix(sort.score(profile), owner));
+        key
+    }
+    
+    /// The part of a ranking key that follows the sort key prefix
+    fn rank_suffix(score: u64, owner: &str) -> Vec<u8> {
+        let mut suffix = (u64::MAX - score).to_be_bytes().to_vec();
+        suffix.extend_from_slice(owner.as_bytes());
+        suffix
+    }
+    
+    /// Make a rank count key. Counts form a 256-ary tree over the big-endian
+    /// inverted score of `rank_key`: the node of a byte prefix holds the number
+    /// of ranked profiles whose inverted score starts with it. The prefix length
+    /// is stored after the sort key so each level of the tree can be scanned on its own.
+    fn count_key(sort: ProfileSort, score_prefix: &[u8]) -> Vec<u8> {
+        let mut key = vec![sort.tag(), score_prefix.len() as u8];
+        key.extend_from_slice(score_prefix);
+        key
+    }
+    
+    /// Encode a leaderboard cursor pointing at a profile's ranking entry
+    pub fn encode_cursor(sort: ProfileSort, profile: &PlayerProfile, owner: &str) -> String {
+        format!("{}:{}", sort.score(profile), owner)
+    }
+    
+    /// Decode a leaderboard cursor into a score and owner
+    fn decode_cursor(cursor: &str) -> Option<(u64, &str)> {
+        let (score, owner) = cursor.split_once(':')?;
+        let score = score.parse::<u64>().ok()?;
+        Some((score, owner))
+    }
+    
+    /// Move a profile's ranking entries from its old to its new values;
+    /// `None` means the profile did not exist before or no longer exists,
+    /// and private profiles are left out of the rankings
+    ///
+    /// Entries are only counted when they are actually added or removed, so
+    /// profiles created before the rankings existed can be indexed at any time.
+    pub async fn update_rankings(
+        &mut self,
+        owner: &str,
+        old: Option<&PlayerProfile>,
+        new: Option<&PlayerProfile>,
+    ) -> Result<(), ViewError> {
+        for sort in ProfileSort::ALL {
+            let mut removed = None;
+            if let Some(old) = old {
+                let key = Self::rank_key(sort, old, owner);
+                if self.rankings.contains_key(&key).await? {
+                    self.rankings.remove(key);
+                    removed = Some(sort.score(old));
+                }
+            }
+            let mut added = None;
+            if let Some(new) = new.filter(|profile| !profile.private) {
+                let key = Self::rank_key(sort, new, owner);
+                if !self.rankings.contains_key(&key).await? {
+                    self.rankings.insert(key, ());
+                    added = Some(sort.score(new));
+                }
+            }
+            self.update_rank_counts(sort, removed, added).await?;
+        }
+        Ok(())
+    }
+    
+    /// Move a profile from the rank count nodes of its `old` score to those
+    /// of its `new` one (None if not ranked). Shared prefixes are left alone.
+    async fn update_rank_counts(
+        &mut self,
+        sort: ProfileSort,
+        old: Option<u64>,
+        new: Option<u64>,
+    ) -> Result<(), ViewError> {
+        let old = old.map(|score| (u64::MAX - score).to_be_bytes());
+        let new = new.map(|score| (u64::MAX - score).to_be_bytes());
+        for len in 1..=8 {
+            let old_prefix = old.as_ref().map(|old| &old[..len]);
+            let new_prefix = new.as_ref().map(|new| &new[..len]);
+            if old_prefix == new_prefix {
+                continue;
+            }
+            if let Some(prefix) = old_prefix {
+                let key = Self::count_key(sort, prefix);
+                let count = self.rank_counts.get(&key).await?.unwrap_or(0);
+                if count <= 1 {
+                    self.rank_counts.remove(key);
+                } else {
+                    self.rank_counts.insert(key, count - 1);
+                }
+            }
+            if let Some(prefix) = new_prefix {
+                let key = Self::count_key(sort, prefix);
+                let count = self.rank_counts.get(&key).await?.unwrap_or(0);
+                self.rank_counts.insert(key, count + 1);
+            }
+        }
+        Ok(())
+    }
+    
+    /// Get the next bytes of the rank count nodes one level below `score_prefix`,
+    /// with the number of profiles under each, in ranking order
+    async fn rank_count_children(
+        &self,
+        sort: ProfileSort,
+        score_prefix: &[u8],
+    ) -> Result<Vec<(u8, u64)>, ViewError> {
+        // Keys of the child level, up to their last byte
+        let mut prefix = vec![sort.tag(), score_prefix.len() as u8 + 1];
+        prefix.extend_from_slice(score_prefix);
+        
+        let mut children = Vec::new();
+        self.rank_counts.for_each_key_value_while(|suffix, count| {
+            children.push((suffix[0], count.into_owned()));
+            Ok(true)
+        }, prefix).await?;
+        Ok(children)
+    }
+    
+    /// Append the owners of the ranking entries under `prefix` to `owners`
+    /// until it holds `limit`, skipping entries up to and including `after`
+    async fn collect_ranked(
+        &self,
+        prefix: Vec<u8>,
+        after: Option<&[u8]>,
+        owners: &mut Vec<String>,
+        limit: usize,
+    ) -> Result<(), ViewError> {
+        // The sort key byte and the inverted score make up 9 bytes
+        let owner_start = 9 - prefix.len();
+        self.rankings.for_each_key_while(|suffix| {
+            if owners.len() >= limit {
+                return Ok(false);
+            }
+            if after.is_some_and(|after| suffix <= after) {
+                return Ok(true);
+            }
+            if let Ok(owner) = std::str::from_utf8(&suffix[owner_start..]) {
+                owners.push(owner.to_string());
+            }
+            Ok(true)
+        }, prefix).await
+    }
+    
+    /// Get the owners of up to `limit` public profiles in ranking order,
+    /// starting after `cursor`
+    ///
+    /// Only profiles tied with the cursor are walked to find where to start;
+    /// lower scores are read subtree by subtree using the rank count tree.
+    /// Returns `None` if the cursor is malformed.
+    pub async fn ranked_owners(
+        &self,
+        sort: ProfileSort,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> Result<Option<Vec<String>>, ViewError> {
+        let mut owners = Vec::new();
+        let (score, after) = match cursor {
+            Some(cursor) => match Self::decode_cursor(cursor) {
+                Some(decoded) => decoded,
+                None => return Ok(None),
+            },
+            None => {
+                self.collect_ranked(vec![sort.tag()], None, &mut owners, limit).await?;
+                return Ok(Some(owners));
+            }
+        };
+        let inverted_score = (u64::MAX - score).to_be_bytes();
+        
+        // Profiles tied with the cursor that sort after it
+        let mut prefix = vec![sort.tag()];
+        prefix.extend_from_slice(&inverted_score);
+        self.collect_ranked(prefix, Some(after.as_bytes()), &mut owners, limit).await?;
+        
+        // Then the subtrees of lower scores, nearest first
+        for len in (0..inverted_score.len()).rev() {
+            for (byte, _) in self.rank_count_children(sort, &inverted_score[..len]).await? {
+                if owners.len() >= limit {
+                    return Ok(Some(owners));
+                }
+                if byte <= inverted_score[len] {
+                    continue;
+                }
+                let mut prefix = vec![sort.tag()];
+                prefix.extend_from_slice(&inverted_score[..len]);
+                prefix.push(byte);
+                self.collect_ranked(prefix, None, &mut owners, limit).await?;
+            }
+        }
+        Ok(Some(owners))
+    }
+    
+    /// Get up to `limit` profiles in storage order, starting after the owner `after`
+    pub async fn profiles_after(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<(String, PlayerProfile)>, ViewError> {
+        let mut skipping = after.is_some();
+        let mut profiles = Vec::new();
+        self.profiles.for_each_index_value_while(|owner, profile| {
+            if profiles.len() >= limit {
+                return Ok(false);
+            }
+            if skipping {
+                skipping = after != Some(owner.as_str());
+                return Ok(true);
+            }
+            profiles.push((owner, profile.into_owned()));
+            Ok(true)
+        }).await?;
+        Ok(profiles)
+    }
+    
+    /// Get a public profile's 1-based rank by a sort key
+    ///
+    /// Profiles with a higher score are counted from the rank count tree, one
+    /// level per byte of score; only profiles tied on score are walked.
+    pub async fn get_rank(&self, owner: &str, sort: ProfileSort) -> Result<Option<u32>, ViewError> {
+        let Some(profile) = self.profiles.get(owner).await? else {
+            return Ok(None);
+        };
+        if profile.private {
+            return Ok(None);
+        }
+        let inverted_score = (u64::MAX - sort.score(&profile)).to_be_bytes();
+        
+        let mut ahead = 0;
+        for len in 0..inverted_score.len() {
+            ahead += self.rank_count_children(sort, &inverted_score[..len]).await?
+                .into_iter()
+                .filter(|(byte, _)| *byte < inverted_score[len])
+                .map(|(_, count)| count)
+                .sum::<u64>();
+        }
+        
+        let mut prefix = vec![sort.tag()];
+        prefix.extend_from_slice(&inverted_score);
+        let mut found = false;
+        self.rankings.for_each_key_while(|tied_owner| {
+            if tied_owner >= owner.as_bytes() {
+                found = tied_owner == owner.as_bytes();
+                return Ok(false);
+            }
+            ahead += 1;
+            Ok(true)
+        }, prefix).await?;
+        // Profiles created before the rankings existed are not indexed until backfilled
+        Ok(found.then_some(ahead as u32 + 1))
+    }
+    
+    /// Make a badge holder key: the badge ID, a zero byte, then the owner
+    pub fn holder_key(badge_id: &str, owner: &str) -> Vec<u8> {
+        let mut key = Self::holder_prefix(badge_id);
+        key.extend_from_slice(owner.as_bytes());
+        key
+    }
+    
+    /// The part of a badge holder key identifying the badge
+    fn holder_prefix(badge_id: &str) -> Vec<u8> {
+        let mut prefix = badge_id.as_bytes().to_vec();
+        prefix.push(0);
+        prefix
+    }
+    
+    /// Get up to `limit` holders of a badge in owner order, starting after `after`
+    pub async fn get_badge_holders(&self, badge_id: &str, after: Option<&str>, limit: usize) -> Vec<String> {
+        let mut owners = Vec::new();
+        self.badge_holders.for_each_key_while(|owner| {
+            if owners.len() >= limit {
+                return Ok::<_, ViewError>(false);
+            }
+            if after.is_some_and(|after| owner <= after.as_bytes()) {
+                return Ok(true);
+            }
+            if let Ok(owner) = std::str::from_utf8(owner) {
+                owners.push(owner.to_string());
+            }
+            Ok(true)
+        }, Self::holder_prefix(badge_id)).await.ok();
+        owners
+    }
+    
+    /// Get the badges awarded to an owner
+    pub async fn get_player_badges(&self, owner: &str) -> Vec<AwardedBadge> {
+        self.player_badges.get(owner).await.ok().flatten().unwrap_or_default()
+    }
+    
+    /// Map an account owner or chain ID to its canonical owner address
+    /// (itself when it is not linked to another profile)
+    pub async fn resolve_owner(&self, identity: &str) -> String {
+        self.identity_links.get(identity).await.ok().flatten()
+            .unwrap_or_else(|| identity.to_string())
+    }
+    
+    /// Get the identities linked to a canonical owner
+    pub async fn get_linked_identities(&self, owner: &str) -> Vec<String> {
+        self.linked_identities.get(owner).await.ok().flatten().unwrap_or_default()
+    }
+    
+    /// Make a relation key from the player it belongs to and the other player
+    fn relation_key(owner: &str, player: &str) -> Vec<u8> {
+        format!("{}:{}", owner, player).into_bytes()
+    }
+    
+    /// Get how one player relates to another (empty if unrelated)
+    pub async fn get_relation(&self, owner: &str, player: &str) -> Result<Relation, ViewError> {
+        Ok(self.relations.get(&Self::relation_key(owner, player)).await?.unwrap_or_default())
+    }
+    
+    /// Get every player an owner is related to, with the relation
+    pub async fn get_relations(&self, owner: &str) -> Result<Vec<(String, Relation)>, ViewError> {
+        let mut relations = Vec::new();
+        self.relations.for_each_key_value_while(|player, relation| {
+            if let Ok(player) = std::str::from_utf8(player) {
+                relations.push((player.to_string(), relation.into_owned()));
+            }
+            Ok(true)
+        }, format!("{}:", owner).into_bytes()).await?;
+        Ok(relations)
+    }
+    
+    /// Store how one player relates to another, keeping the player's list sizes in sync
+    pub async fn save_relation(&mut self, owner: &str, player: &str, old: &Relation, new: Relation) -> Result<(), ViewError> {
+        let mut counts = self.get_relationship_counts(owner).await?;
+        counts.apply(old, &new);
+        if counts == RelationshipCounts::default() {
+            self.relationship_counts.remove(owner)?;
+        } else {
+            self.relationship_counts.insert(owner, counts)?;
+        }
+        
+        let key = Self::relation_key(owner, player);
+        if new.is_empty() {
+            self.relations.remove(key);
+        } else {
+            self.relations.insert(key, new);
+        }
+        Ok(())
+    }
+    
+    /// Get the size of each of a player's relationship lists
+    pub async fn get_relationship_counts(&self, owner: &str) -> Result<RelationshipCounts, ViewError> {
+        Ok(self.relationship_counts.get(owner).await?.unwrap_or_default())
+    }
+    
+    /// Get a player's relationships (empty if none recorded)
+    pub async fn get_relationships(&self, owner: &str) -> Result<Relationships, ViewError> {
+        let mut relationships = Relationships::default();
+        for (player, relation) in self.get_relations(owner).await? {
+            relationships.insert(&player, &relation);
+        }
+        Ok(relationships)
+    }
+    
+    /// Check whether either player has blocked the other
+    pub async fn is_blocked_between(&self, player_a: &str, player_b: &str) -> Result<bool, ViewError> {
+        let relation = self.get_relation(player_a, player_b).await?;
+        Ok(relation.blocked || relation.blocked_by)
+    }
+    
+    /// Make a key of the link requests a profile has made
+    fn link_request_key(owner: &str, identity: &str) -> Vec<u8> {
+        format!("{}:{}", owner, identity).into_bytes()
+    }
+    
+    /// Record that a profile asked to link an identity
+    pub fn add_link_request(&mut self, owner: &str, identity: &str) -> Result<(), ViewError> {
+        self.pending_links.insert(identity, owner.to_string())?;
+        self.link_requests.insert(Self::link_request_key(owner, identity), ());
+        Ok(())
+    }
+    
+    /// Drop a pending link request for an identity
+    pub fn remove_link_request(&mut self, owner: &str, identity: &str) -> Result<(), ViewError> {
+        self.pending_links.remove(identity)?;
+        self.link_requests.remove(Self::link_request_key(owner, identity));
+        Ok(())
+    }
+    
+    /// Get the identities a profile has asked to link
+    pub async fn get_link_requests(&self, owner: &str) -> Result<Vec<String>, ViewError> {
+        let mut identities = Vec::new();
+        self.link_requests.for_each_key_while(|identity| {
+            if let Ok(identity) = std::str::from_utf8(identity) {
+                identities.push(identity.to_string());
+            }
+            Ok(true)
+        }, format!("{}:", owner).into_bytes()).await?;
+        Ok(identities)
+    }
+    
+    /// Get every game's stats for an owner
+    pub async fn get_game_stats(&self, owner: &str) -> Vec<GameStats> {
+        let mut games = Vec::new();
+        self.player_games.for_each_key_value_while(|_, game| {
+            games.push(game.into_owned());
+            Ok::<_, ViewError>(true)
+        }, format!("{}:", owner).into_bytes()).await.ok();
+        
+        let mut stats = Vec::new();
+        for game in games {
+            if let Some(game_stats) = self.get_game_stat(owner, &game).await.ok().flatten() {
+                stats.push(game_stats);
+            }
+        }
+        stats
+    }
 }
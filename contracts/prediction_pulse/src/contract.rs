@@ -9,7 +9,9 @@ use linera_sdk::{
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use prediction_pulse::{Bet, Operation, PredictionPulseAbi, Round, RoundStatus};
+use prediction_pulse::{Bet, Operation, PredictionPulseAbi, PredictionPulseParameters, Round, RoundStatus, BET_XP, WIN_XP};
+use arcade_reporting::{report_event, report_stats, report_xp};
+use arcade_nexus::GameEventKind;
 use state::PredictionPulseState;
 
 /// The PredictionPulse contract.
@@ -27,7 +29,7 @@ impl WithContractAbi for PredictionPulseContract {
 impl Contract for PredictionPulseContract {
     type Message = ();
     type InstantiationArgument = ();
-    type Parameters = ();
+    type Parameters = PredictionPulseParameters;
     type EventValue = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -108,6 +110,9 @@ impl Contract for PredictionPulseContract {
                         stats.rounds_played += 1;
                         stats.total_wagered = stats.total_wagered.saturating_add(amount);
                         self.state.save_player_stats(&owner, stats).await;
+
+                        report_xp(&mut self.runtime, BET_XP);
+                        report_stats(&mut self.runtime, BET_XP, 1, 0);
                     }
                 }
             }
@@ -175,6 +180,10 @@ impl Contract for PredictionPulseContract {
                                     stats.rounds_won += 1;
                                     stats.total_won = stats.total_won.saturating_add(winnings);
                                     self.state.save_player_stats(&owner, stats).await;
+
+                                    report_xp(&mut self.runtime, WIN_XP);
+                                    report_stats(&mut self.runtime, WIN_XP, 0, 1);
+                                    report_event(&mut self.runtime, GameEventKind::PredictionWin, 1);
                                 }
                             }
                         }
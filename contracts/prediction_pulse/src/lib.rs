@@ -18,6 +18,15 @@ impl linera_sdk::linera_base_types::ServiceAbi for PredictionPulseAbi {
     type QueryResponse = async_graphql::Response;
 }
 
+/// Applications this game reports player results to, fixed at creation.
+pub type PredictionPulseParameters = arcade_reporting::ReportingParameters;
+
+/// Season XP for placing a bet.
+pub const BET_XP: u64 = 10;
+
+/// Season XP for claiming the winnings of a correct prediction.
+pub const WIN_XP: u64 = 75;
+
 /// Status of a prediction round.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
 pub enum RoundStatus {
@@ -12,7 +12,7 @@ use linera_sdk::{
     views::{RootView, View},
     Service, ServiceRuntime,
 };
-use prediction_pulse::{Bet, Operation, PlayerStats, PredictionPulseAbi, Round, RoundStatus};
+use prediction_pulse::{Bet, Operation, PlayerStats, PredictionPulseAbi, PredictionPulseParameters, Round, RoundStatus};
 use state::PredictionPulseState;
 
 /// The PredictionPulse service.
@@ -28,7 +28,7 @@ impl WithServiceAbi for PredictionPulseService {
 }
 
 impl Service for PredictionPulseService {
-    type Parameters = ();
+    type Parameters = PredictionPulseParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = PredictionPulseState::load(runtime.root_view_storage_context())
@@ -9,7 +9,9 @@ use linera_sdk::{
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use typing_arena::{Challenge, ChallengeStatus, Operation, TypingArenaAbi, TypingResult};
+use typing_arena::{Challenge, ChallengeStatus, Operation, TypingArenaAbi, TypingArenaParameters, TypingResult, COMPLETION_XP, PARTICIPATION_XP};
+use arcade_reporting::{report_event, report_stats, report_xp};
+use arcade_nexus::GameEventKind;
 use state::TypingArenaState;
 
 /// The TypingArena contract.
@@ -27,7 +29,7 @@ impl WithContractAbi for TypingArenaContract {
 impl Contract for TypingArenaContract {
     type Message = ();
     type InstantiationArgument = ();
-    type Parameters = ();
+    type Parameters = TypingArenaParameters;
     type EventValue = ();
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -184,6 +186,13 @@ impl Contract for TypingArenaContract {
                         }
                         
                         self.state.save_player_stats(&owner, stats).await;
+
+                        let xp = if completed { COMPLETION_XP } else { PARTICIPATION_XP };
+                        report_xp(&mut self.runtime, xp);
+                        report_stats(&mut self.runtime, xp, 1, 0);
+                        if completed {
+                            report_event(&mut self.runtime, GameEventKind::TypingChallengeCompleted, 1);
+                        }
                     }
                 }
             }
@@ -20,6 +20,15 @@ impl linera_sdk::linera_base_types::ServiceAbi for TypingArenaAbi {
     type QueryResponse = async_graphql::Response;
 }
 
+/// Applications this game reports player results to, fixed at creation.
+pub type TypingArenaParameters = arcade_reporting::ReportingParameters;
+
+/// Season XP for submitting a result without finishing the text.
+pub const PARTICIPATION_XP: u64 = 5;
+
+/// Season XP for finishing the text of a challenge.
+pub const COMPLETION_XP: u64 = 20;
+
 /// Status of a typing challenge.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, async_graphql::Enum)]
 pub enum ChallengeStatus {
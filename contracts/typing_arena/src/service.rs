@@ -12,7 +12,7 @@ use linera_sdk::{
     views::{RootView, View},
     Service, ServiceRuntime,
 };
-use typing_arena::{Challenge, ChallengeStatus, Operation, TypingArenaAbi, TypingResult, TypistStats};
+use typing_arena::{Challenge, ChallengeStatus, Operation, TypingArenaAbi, TypingArenaParameters, TypingResult, TypistStats};
 use state::TypingArenaState;
 
 /// The TypingArena service.
@@ -28,7 +28,7 @@ impl WithServiceAbi for TypingArenaService {
 }
 
 impl Service for TypingArenaService {
-    type Parameters = ();
+    type Parameters = TypingArenaParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = TypingArenaState::load(runtime.root_view_storage_context())
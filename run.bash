@@ -80,6 +80,7 @@ if [ -d "${CONTRACTS_DIR}/player_profile" ]; then
     echo "  Deploying PlayerProfile to local network..."
     PLAYER_PROFILE_APP_ID=$(linera publish-and-create \
         "$CONTRACT_WASM" "$SERVICE_WASM" \
+        --json-parameters '{"level_curve":{"thresholds":[100,250,500,1000,2000,4000,8000,16000,32000]},"name_change_cooldown_ms":604800000}' \
         2>&1 | grep -oP 'Application ID: \K.*' || echo "")
     
     if [ -n "$PLAYER_PROFILE_APP_ID" ]; then
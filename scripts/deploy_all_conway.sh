@@ -35,6 +35,19 @@ ENV_FILE="$ROOT/.env"
 SEND_TIMEOUT=60000     # 60 seconds
 RECV_TIMEOUT=600000    # 10 minutes
 
+# Games report player XP to Arcade Nexus when its APP ID is provided, and
+# lifetime stats to the PlayerProfile application deployed below
+ARCADE_NEXUS_APP_ID="${ARCADE_NEXUS_APP_ID:-}"
+
+# Build the game parameters from the applications games report to
+game_parameters() {
+    local fields=()
+    [ -n "$ARCADE_NEXUS_APP_ID" ] && fields+=("\"arcade_nexus\":\"$ARCADE_NEXUS_APP_ID\"")
+    [ -n "$PLAYER_PROFILE_APP_ID" ] && fields+=("\"player_profile\":\"$PLAYER_PROFILE_APP_ID\"")
+    local IFS=,
+    echo "{${fields[*]}}"
+}
+
 # Helper function to upsert environment variables in .env
 upsert_env() {
     local key="$1"
@@ -163,6 +176,7 @@ if [ -f "$TARGET/player_profile_contract.opt.wasm" ] && [ -f "$TARGET/player_pro
         publish-and-create \
         "$TARGET/player_profile_contract.opt.wasm" \
         "$TARGET/player_profile_service.opt.wasm" \
+        --json-parameters "{}" \
         --json-argument "null" 2>&1 | tail -1) || true
     if [ -n "$PLAYER_PROFILE_APP_ID" ]; then
         echo "   ✓ player_profile: $PLAYER_PROFILE_APP_ID"
@@ -172,6 +186,7 @@ if [ -f "$TARGET/player_profile_contract.opt.wasm" ] && [ -f "$TARGET/player_pro
 else
     echo "   ⚠ player_profile WASM not found"
 fi
+GAME_PARAMETERS=$(game_parameters)
 
 # Deploy game_of_life
 if [ -f "$TARGET/game_of_life_contract.opt.wasm" ] && [ -f "$TARGET/game_of_life_service.opt.wasm" ]; then
@@ -182,6 +197,7 @@ if [ -f "$TARGET/game_of_life_contract.opt.wasm" ] && [ -f "$TARGET/game_of_life
         publish-and-create \
         "$TARGET/game_of_life_contract.opt.wasm" \
         "$TARGET/game_of_life_service.opt.wasm" \
+        --json-parameters "$GAME_PARAMETERS" \
         --json-argument "null" 2>&1 | tail -1) || true
     if [ -n "$GOL_APP_ID" ]; then
         echo "   ✓ game_of_life: $GOL_APP_ID"
@@ -201,6 +217,7 @@ if [ -f "$TARGET/prediction_pulse_contract.opt.wasm" ] && [ -f "$TARGET/predicti
         publish-and-create \
         "$TARGET/prediction_pulse_contract.opt.wasm" \
         "$TARGET/prediction_pulse_service.opt.wasm" \
+        --json-parameters "$GAME_PARAMETERS" \
         --json-argument "null" 2>&1 | tail -1) || true
     if [ -n "$PREDICTION_PULSE_APP_ID" ]; then
         echo "   ✓ prediction_pulse: $PREDICTION_PULSE_APP_ID"
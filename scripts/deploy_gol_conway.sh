@@ -63,10 +63,18 @@ echo ""
 echo "Deploying to Conway testnet..."
 echo ""
 
-# Game of Life doesn't need instantiation arguments
+# Game of Life doesn't need instantiation arguments; it reports player XP
+# to Arcade Nexus when ARCADE_NEXUS_APP_ID is set,
+# and stats to PlayerProfile when PLAYER_PROFILE_APP_ID is set
+FIELDS=()
+[ -n "${ARCADE_NEXUS_APP_ID:-}" ] && FIELDS+=("\"arcade_nexus\":\"$ARCADE_NEXUS_APP_ID\"")
+[ -n "${PLAYER_PROFILE_APP_ID:-}" ] && FIELDS+=("\"player_profile\":\"$PLAYER_PROFILE_APP_ID\"")
+GAME_PARAMETERS="{$(IFS=,; echo "${FIELDS[*]}")}"
+
 APPLICATION_ID=$(linera publish-and-create \
     "$CONTRACT_WASM" \
     "$SERVICE_WASM" \
+    --json-parameters "$GAME_PARAMETERS" \
     --json-argument "null" \
     2>&1 | tail -1)
 
@@ -33,10 +33,17 @@ echo "🔨 Building Meme Battle contract..."
 cargo build --release --target wasm32-unknown-unknown --package meme_battle
 
 echo "� Publishing bytecode and creating application..."
-# Use publish-and-create command (no initialization arguments needed)
+# Use publish-and-create command (no initialization arguments needed);
+# votes are reported to Arcade Nexus when ARCADE_NEXUS_APP_ID is set
+# and to PlayerProfile when PLAYER_PROFILE_APP_ID is set
+FIELDS=()
+[ -n "${ARCADE_NEXUS_APP_ID:-}" ] && FIELDS+=("\"arcade_nexus\":\"$ARCADE_NEXUS_APP_ID\"")
+[ -n "${PLAYER_PROFILE_APP_ID:-}" ] && FIELDS+=("\"player_profile\":\"$PLAYER_PROFILE_APP_ID\"")
+GAME_PARAMETERS="{$(IFS=,; echo "${FIELDS[*]}")}"
 RESULT=$(linera publish-and-create \
     target/wasm32-unknown-unknown/release/meme_battle_contract.wasm \
     target/wasm32-unknown-unknown/release/meme_battle_service.wasm \
+    --json-parameters "$GAME_PARAMETERS" \
     2>&1)
 
 echo "$RESULT"
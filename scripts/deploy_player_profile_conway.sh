@@ -67,6 +67,7 @@ echo ""
 APPLICATION_ID=$(linera publish-and-create \
     "$CONTRACT_WASM" \
     "$SERVICE_WASM" \
+    --json-parameters '{"level_curve":{"thresholds":[100,250,500,1000,2000,4000,8000,16000,32000]},"name_change_cooldown_ms":604800000}' \
     --json-argument "null" \
     2>&1 | tail -1)
 
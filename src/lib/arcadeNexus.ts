@@ -433,35 +433,6 @@ export async function closeSeason(seasonId: number): Promise<void> {
   }
 }
 
-/**
- * Record a game action to earn XP
- * This should be called after completing game actions
- */
-export async function recordGameAction(
-  seasonId: number,
-  category: GameCategory,
-  points: number
-): Promise<void> {
-  const appId = getArcadeNexusAppId()
-  const graphqlCategory = categoryToGraphQL(category)
-  
-  console.log('[ArcadeNexus] Recording game action:', { seasonId, category: graphqlCategory, points })
-  
-  try {
-    const result = await mutateApplication(appId, `
-      mutation {
-        recordGameAction(seasonId: ${seasonId}, category: ${graphqlCategory}, points: ${points})
-      }
-    `) as { errors?: Array<{ message: string }> }
-    
-    handleGraphQLErrors(result)
-    console.log('[ArcadeNexus] Game action recorded, XP earned:', points)
-  } catch (error) {
-    console.error('[ArcadeNexus] Failed to record game action:', error)
-    throw error
-  }
-}
-
 /**
  * Create a quest (admin only)
  */
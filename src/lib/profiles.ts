@@ -8,10 +8,13 @@
  * Set VITE_PLAYER_PROFILE_APP_ID in your .env file after deployment.
  * 
  * NO DEMO/MOCK CODE - All operations hit the real Conway network.
+ * 
+ * Stats (XP, games played, wins) are reported by the game contracts
+ * themselves; the PlayerProfile contract rejects them from users.
  */
 
 import {
-    getCurrentChainId,
+    getCurrentOwner,
     mutateApplication,
     queryApplication,
 } from './lineraClient'
@@ -57,10 +60,10 @@ function getProfileAppId(): string {
  */
 export async function fetchCurrentProfile(): Promise<PlayerProfile | null> {
   const appId = getProfileAppId()
-  const chainId = getCurrentChainId()
+  const owner = getCurrentOwner()
   
-  if (!chainId) {
-    console.warn('[Profiles] No chain ID available - connect wallet first')
+  if (!owner) {
+    console.warn('[Profiles] No account owner available - connect wallet first')
     return null
   }
   
@@ -75,7 +78,7 @@ export async function fetchCurrentProfile(): Promise<PlayerProfile | null> {
           wins
         }
       }
-    `, { owner: chainId }) as { data?: { profile?: PlayerProfile } }
+    `, { owner }) as { data?: { profile?: PlayerProfile } }
     
     return result?.data?.profile || null
   } catch (error) {
@@ -89,16 +92,16 @@ export async function fetchCurrentProfile(): Promise<PlayerProfile | null> {
  */
 export async function hasProfile(): Promise<boolean> {
   const appId = getProfileAppId()
-  const chainId = getCurrentChainId()
+  const owner = getCurrentOwner()
   
-  if (!chainId) return false
+  if (!owner) return false
   
   try {
     const result = await queryApplication(appId, `
       query HasProfile($owner: String!) {
         hasProfile(owner: $owner)
       }
-    `, { owner: chainId }) as { data?: { hasProfile?: boolean } }
+    `, { owner }) as { data?: { hasProfile?: boolean } }
     
     return result?.data?.hasProfile || false
   } catch (error) {
@@ -138,30 +141,26 @@ export async function registerProfile(name: string): Promise<boolean> {
 }
 
 /**
- * Update profile stats (XP, games played, wins)
+ * Move a profile created when profiles were keyed by chain ID to the
+ * current account owner
  */
-export async function updateStats(
-  xpDelta: number = 0,
-  gamesDelta: number = 0,
-  winsDelta: number = 0
-): Promise<PlayerProfile | null> {
+export async function migrateChainProfile(): Promise<boolean> {
   const appId = getProfileAppId()
   
   try {
     const result = await mutateApplication(appId, `
-      mutation UpdateStats($xpDelta: Int!, $gamesDelta: Int!, $winsDelta: Int!) {
-        updateStats(xpDelta: $xpDelta, gamesDelta: $gamesDelta, winsDelta: $winsDelta)
+      mutation {
+        migrateChainProfile
       }
-    `, { xpDelta, gamesDelta, winsDelta }) as { data?: unknown, errors?: Array<{ message: string }> }
+    `) as { data?: unknown, errors?: Array<{ message: string }> }
     
     if (result?.errors?.length) {
       throw new Error(result.errors[0].message)
     }
     
-    // Refresh and return updated profile
-    return await fetchCurrentProfile()
+    return true
   } catch (error) {
-    console.error('[Profiles] Failed to update stats:', error)
+    console.error('[Profiles] Failed to migrate profile:', error)
     throw error
   }
 }
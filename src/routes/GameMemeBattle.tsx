@@ -10,7 +10,6 @@ import {
   TrophyIcon
 } from '../components/Icons';
 import { useWallet } from '../contexts/WalletContext';
-import * as nexus from '../lib/arcadeNexus';
 import type { Match, Tournament } from '../lib/memeBattle';
 import * as mb from '../lib/memeBattle';
 import { XP_VALUES } from '../lib/xpConfig';
@@ -34,20 +33,6 @@ function LiveIcon({ size = 16 }: { size?: number }) {
   )
 }
 
-// Helper to record XP (silent failure)
-async function recordBattleXP(xp: number) {
-  if (!nexus.isArcadeNexusConfigured()) return
-  try {
-    const seasons = await nexus.getActiveSeasons()
-    if (seasons.length > 0) {
-      await nexus.recordGameAction(seasons[0].id, 'MEME', xp)
-      console.log('[MemeBattle] Recorded', xp, 'XP')
-    }
-  } catch (err) {
-    console.log('[MemeBattle] XP recording skipped:', err)
-  }
-}
-
 export default function GameMemeBattle() {
   const { owner, state } = useWallet()
   const isConnected = state === 'connected' || state === 'ready'
@@ -176,10 +161,7 @@ export default function GameMemeBattle() {
       console.log('[MemeBattle] Calling voteMeme...')
       await mb.voteMeme(selectedTournament.tournamentId, matchId, memeId)
       
-      console.log('[MemeBattle] Vote successful! Recording XP...')
-      
-      // Record XP
-      await recordBattleXP(XP_VALUES.meme.bid) // +10 XP for voting
+      console.log('[MemeBattle] Vote successful!')
       
       // Mark as voted
       setVotedMatches(prev => new Set(prev).add(matchId))